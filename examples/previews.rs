@@ -8,14 +8,16 @@ use std::io::{stdout, Write};
 use std::path::Path;
 
 use nominals::{
-    ArmenianLower, ArmenianUpper, Bengali, Cambodian, CircledNumber, CjkDecimal, CjkEarthlyBranch,
-    CjkHeavenlyStem, Decimal, Devanagari, DigitCollection, DoubleCircledNumber, EasternArabic,
-    Ethiopic, Georgian, GreekLower, GreekUpper, Gujarati, Gurmukhi, HangeulFormal,
-    HangeulJamo, HangeulSyllable, HanjaFormal, HanjaInformal, Hebrew, HexLower, HexUpper, Hiragana,
-    HiraganaIroha, JapaneseFormal, JapaneseInformal, Kannada, Katakana, KatakanaIroha, Lao,
-    LetterLower, LetterUpper, Malayalam, Mongolian, Myanmar, Nominal, NominalString, NominalSystem,
-    Oriya, Persian, RomanLower, RomanUpper, SimplifiedChineseFormal, SimplifiedChineseInformal,
-    Tamil, Telugu, Thai, Tibetan, TraditionalChineseFormal, TraditionalChineseInformal,
+    AbjadArabic, AbjadPersian, ArmenianLower, ArmenianUpper, Bengali, Cambodian, CircledNumber,
+    CjkDecimal, CjkEarthlyBranch, CjkHeavenlyStem, CyrillicNumeral, Decimal, Devanagari,
+    DigitCollection, DoubleCircledNumber, EasternArabic, Ethiopic, FootnoteSymbol, Georgian,
+    GlagoliticNumeral, GreekLower, GreekNumeralLower, GreekNumeralUpper, GreekUpper, Gujarati,
+    Gurmukhi, HangeulFormal, HangeulJamo, HangeulSyllable, HanjaFormal, HanjaInformal, Hebrew,
+    HexLower, HexUpper, Hiragana, HiraganaIroha, JapaneseFormal, JapaneseInformal, Kannada,
+    Katakana, KatakanaIroha, Lao, LetterLower, LetterUpper, Malayalam, Mongolian, Myanmar, Nominal,
+    NominalString, NominalSystem, Oriya, Persian, RomanLower, RomanUpper, Sexagenary,
+    SimplifiedChineseFormal, SimplifiedChineseInformal, Tamil, Telugu, Thai, Tibetan,
+    TraditionalChineseFormal, TraditionalChineseInformal,
 };
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
@@ -41,16 +43,24 @@ fn main() {
         preview(&DigitPreview(CjkDecimal)),
         preview(&DigitPreview(CjkEarthlyBranch)),
         preview(&DigitPreview(CjkHeavenlyStem)),
+        preview(&Sexagenary::default()),
         preview(&DigitPreview(Devanagari)),
         preview(&DigitPreview(EasternArabic)),
         preview(&Ethiopic),
+        preview(&FootnoteSymbol),
         preview(&Georgian),
         preview(&DigitPreview(GreekLower)),
         preview(&DigitPreview(GreekUpper)),
+        preview(&GreekNumeralLower::default()),
+        preview(&GreekNumeralUpper::default()),
+        preview(&CyrillicNumeral),
+        preview(&GlagoliticNumeral),
+        preview(&AbjadArabic::default()),
+        preview(&AbjadPersian::default()),
         preview(&DigitPreview(Gujarati)),
         preview(&DigitPreview(Gurmukhi)),
         preview(&HanjaInformal),
-        preview(&HangeulFormal),
+        preview(&HangeulFormal::default()),
         preview(&DigitPreview(HangeulJamo)),
         preview(&DigitPreview(HangeulSyllable)),
         preview(&HanjaFormal),
@@ -132,11 +142,11 @@ where
 
     print(
         file.as_mut(),
-        format_args!("| {:max_decimal_width$} | {name:header_pad$} |\n", "#",),
+        format_args!("| {:max_decimal_width$} | {name:header_pad$} |\n", "#"),
     );
     print(
         file.as_mut(),
-        format_args!("|-{:-^max_decimal_width$}-|-{:-^nominal_pad$}-|\n", "", "",),
+        format_args!("|-{:-^max_decimal_width$}-|-{:-^nominal_pad$}-|\n", "", ""),
     );
     let mut one_line = String::new();
     for (decimal, nominal) in results {
@@ -349,12 +359,68 @@ impl Previewable for HanjaFormal {
     }
 }
 
+fn greek_numeral_values() -> Vec<u32> {
+    vec![1, 2, 3, 6, 9, 10, 11, 90, 99, 100, 101, 999, 1_000, 1_001]
+}
+
+impl Previewable for GreekNumeralLower {
+    fn preview_values(&self) -> Vec<u32> {
+        greek_numeral_values()
+    }
+}
+
+impl Previewable for GreekNumeralUpper {
+    fn preview_values(&self) -> Vec<u32> {
+        greek_numeral_values()
+    }
+}
+
+impl Previewable for CyrillicNumeral {
+    fn preview_values(&self) -> Vec<u32> {
+        vec![1, 2, 3, 10, 11, 12, 19, 20, 21, 100, 111, 1_000, 1_001]
+    }
+}
+
+impl Previewable for GlagoliticNumeral {
+    fn preview_values(&self) -> Vec<u32> {
+        vec![1, 2, 3, 10, 11, 12, 19, 20, 21, 100, 111, 1_000, 1_001]
+    }
+}
+
+fn abjad_values() -> Vec<u32> {
+    vec![1, 2, 3, 9, 10, 11, 99, 100, 101, 999, 1_000, 1_001, 2_000]
+}
+
+impl Previewable for AbjadArabic {
+    fn preview_values(&self) -> Vec<u32> {
+        abjad_values()
+    }
+}
+
+impl Previewable for AbjadPersian {
+    fn preview_values(&self) -> Vec<u32> {
+        abjad_values()
+    }
+}
+
 impl Previewable for Ethiopic {
     fn preview_values(&self) -> Vec<u32> {
         vec![1, 2, 3, 10, 11, 12, 99, 100, 101, 999, 1000, 1001]
     }
 }
 
+impl Previewable for Sexagenary {
+    fn preview_values(&self) -> Vec<u32> {
+        vec![1, 2, 3, 10, 11, 12, 13, 59, 60, 61]
+    }
+}
+
+impl Previewable for FootnoteSymbol {
+    fn preview_values(&self) -> Vec<u32> {
+        vec![1, 2, 3, 4, 5, 6, 7, 8, 12, 13]
+    }
+}
+
 impl Previewable for CircledNumber {
     fn preview_values(&self) -> Vec<u32> {
         vec![0, 1, 2, 3, 4, 9, 10, 11, 12, 48, 49, 50]
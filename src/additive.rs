@@ -1,4 +1,8 @@
-use crate::{Error, Nominal, NominalString, NominalSystem, WithNominal};
+use crate::succession::step_parsed;
+use crate::{
+    Error, NominalString, NominalSuccession, NominalSystem, OutOfMemoryError, SuccessionError,
+    UnsignedInteger, WithNominal,
+};
 
 /// A set of additive symbols that form a [`NominalSystem`].
 pub struct AdditiveSet<const N: usize> {
@@ -29,16 +33,41 @@ impl<const N: usize> AdditiveSet<N> {
         };
         Self { symbols, zero }
     }
+
+    /// Returns the sum of the symbols in `nominal`, or `None` if `nominal`
+    /// contains text that isn't a symbol from this set.
+    ///
+    /// Symbols are matched greedily in descending value order, which only
+    /// guarantees the correct value for nominals this set formats.
+    fn parse(&self, nominal: &str) -> Option<u128> {
+        if nominal.is_empty() {
+            return None;
+        } else if self.zero == Some(nominal) {
+            return Some(0);
+        }
+
+        let mut remaining = nominal;
+        let mut value = 0_u128;
+        while !remaining.is_empty() {
+            let (symbol, symbol_value) = self
+                .symbols
+                .iter()
+                .find(|(symbol, value)| *value > 0 && remaining.starts_with(symbol))?;
+            value = value.checked_add(*symbol_value)?;
+            remaining = &remaining[symbol.len()..];
+        }
+        Some(value)
+    }
 }
 
 impl<const N: usize, T> NominalSystem<T> for AdditiveSet<N>
 where
-    T: Nominal + TryFrom<u128>,
+    T: UnsignedInteger + TryFrom<u128>,
 {
     fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
         let mut formatted = NominalString::default();
 
-        let mut remaining = nominal;
+        let mut remaining = nominal.clone();
         if remaining.is_zero() {
             return if let Some(zero) = self.zero {
                 Ok(NominalString::from(zero))
@@ -56,8 +85,10 @@ where
                 continue;
             };
             while remaining >= value_as_t {
-                remaining -= value_as_t;
-                formatted.try_push_str(symbol).with_nominal(nominal)?;
+                remaining -= value_as_t.clone();
+                formatted
+                    .try_push_str(symbol)
+                    .with_nominal(nominal.clone())?;
             }
         }
 
@@ -65,550 +96,1166 @@ where
     }
 }
 
-/// Lowercase Roman numerals.
-///
-/// This encoding utilizes Vinculum notation for numbers 4,000 and above. This
-/// notation uses an overline over a repeated set of symbols. A few examples:
-///
-/// ```rust
-/// use nominals::{Nominal, RomanLower};
-///
-/// assert_eq!(4_000_u32.to_nominal(&RomanLower), "i̅v̅");
-/// assert_eq!(4_001_u32.to_nominal(&RomanLower), "i̅v̅i");
-/// assert_eq!(2_000_000_u32.to_nominal(&RomanLower), "m̅m̅");
-/// ```
-#[doc = include_str!("./previews/RomanLower.md")]
-pub struct RomanLower;
-
-impl<T> NominalSystem<T> for RomanLower
-where
-    T: Nominal + TryFrom<u128>,
-{
-    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
-        AdditiveSet::new([
-            ("m̅", 1_000_000),
-            ("d̅m̅", 900_000),
-            ("d̅", 500_000),
-            ("c̅d̅", 400_000),
-            ("c̅", 100_000),
-            ("l̅c̅", 90_000),
-            ("l̅", 50_000),
-            ("x̅l̅", 40_000),
-            ("x̅", 10_000),
-            ("i̅x̅", 9_000),
-            ("v̅", 5_000),
-            ("i̅v̅", 4_000),
-            ("m", 1_000),
-            ("cm", 900),
-            ("d", 500),
-            ("cd", 400),
-            ("c", 100),
-            ("xc", 90),
-            ("l", 50),
-            ("xl", 40),
-            ("x", 10),
-            ("ix", 9),
-            ("v", 5),
-            ("iv", 4),
-            ("i", 1),
-            ("n", 0),
-        ])
-        .try_format_nominal(nominal)
+impl<const N: usize> NominalSuccession for AdditiveSet<N> {
+    fn next_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        step_parsed(
+            nominal,
+            true,
+            |nominal| self.parse(nominal),
+            |value| self.try_format_nominal(value),
+        )
     }
-}
 
-/// Uppercase Roman numerals
-///
-/// This encoding utilizes Vinculum notation for numbers 4,000 and above. This
-/// notation uses an overline over a repeated set of symbols. A few examples:
-///
-/// ```rust
-/// use nominals::{Nominal, RomanUpper};
-///
-/// assert_eq!(4000_u32.to_nominal(&RomanUpper), "I̅V̅");
-/// assert_eq!(4_001_u32.to_nominal(&RomanUpper), "I̅V̅I");
-/// assert_eq!(2_000_000_u32.to_nominal(&RomanUpper), "M̅M̅");
-/// ```
-#[doc = include_str!("./previews/RomanUpper.md")]
-pub struct RomanUpper;
-
-impl<T> NominalSystem<T> for RomanUpper
-where
-    T: Nominal + TryFrom<u128>,
-{
-    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
-        AdditiveSet::new([
-            ("M̅", 1_000_000),
-            ("D̅M̅", 900_000),
-            ("D̅", 500_000),
-            ("C̅D̅", 400_000),
-            ("C̅", 100_000),
-            ("L̅C̅", 90_000),
-            ("L̅", 50_000),
-            ("X̅L̅", 40_000),
-            ("X̅", 10_000),
-            ("I̅X̅", 9_000),
-            ("V̅", 5_000),
-            ("I̅V̅", 4_000),
-            ("M", 1_000),
-            ("CM", 900),
-            ("D", 500),
-            ("CD", 400),
-            ("C", 100),
-            ("XC", 90),
-            ("L", 50),
-            ("XL", 40),
-            ("X", 10),
-            ("IX", 9),
-            ("V", 5),
-            ("IV", 4),
-            ("I", 1),
-            ("N", 0),
-        ])
-        .try_format_nominal(nominal)
+    fn prev_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        step_parsed(
+            nominal,
+            false,
+            |nominal| self.parse(nominal),
+            |value| self.try_format_nominal(value),
+        )
     }
 }
 
-#[test]
-fn roman() {
-    use crate::Nominal;
+macro_rules! impl_additive_preset {
+    ($name:ident, $set:ident) => {
+        impl<T> NominalSystem<T> for $name
+        where
+            T: UnsignedInteger + TryFrom<u128>,
+        {
+            fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+                $set.try_format_nominal(nominal)
+            }
+        }
 
-    assert_eq!(0_u32.to_nominal(&RomanUpper), "N");
-    assert_eq!(1_u32.to_nominal(&RomanUpper), "I");
-    assert_eq!(2_u32.to_nominal(&RomanUpper), "II");
-    assert_eq!(3_u32.to_nominal(&RomanUpper), "III");
-    assert_eq!(4_u32.to_nominal(&RomanUpper), "IV");
-    assert_eq!(5_u32.to_nominal(&RomanUpper), "V");
-    assert_eq!(0_u32.to_nominal(&RomanLower), "n");
-    assert_eq!(1_u32.to_nominal(&RomanLower), "i");
-    assert_eq!(2_u32.to_nominal(&RomanLower), "ii");
-    assert_eq!(3_u32.to_nominal(&RomanLower), "iii");
-    assert_eq!(4_u32.to_nominal(&RomanLower), "iv");
-    assert_eq!(5_u32.to_nominal(&RomanLower), "v");
-    assert_eq!(4000_u32.to_nominal(&RomanLower), "i̅v̅");
-    assert_eq!(2_000_000_u32.to_nominal(&RomanLower), "m̅m̅");
-}
+        impl NominalSuccession for $name {
+            fn next_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+                $set.next_nominal(nominal)
+            }
 
-#[test]
-fn long_test() {
-    assert_eq!(
-        63_000_000_u32.to_nominal(&RomanLower),
-        "m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅"
-    );
+            fn prev_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+                $set.prev_nominal(nominal)
+            }
+        }
+    };
 }
 
+const ARMENIAN_UPPER: AdditiveSet<36> = AdditiveSet::new([
+    ("Ք", 9000),
+    ("Փ", 8000),
+    ("Ւ", 7000),
+    ("Ց", 6000),
+    ("Ր", 5000),
+    ("Տ", 4000),
+    ("Վ", 3000),
+    ("Ս", 2000),
+    ("Ռ", 1000),
+    ("Ջ", 900),
+    ("Պ", 800),
+    ("Չ", 700),
+    ("Ո", 600),
+    ("Շ", 500),
+    ("Ն", 400),
+    ("Յ", 300),
+    ("Մ", 200),
+    ("Ճ", 100),
+    ("Ղ", 90),
+    ("Ձ", 80),
+    ("Հ", 70),
+    ("Կ", 60),
+    ("Ծ", 50),
+    ("Խ", 40),
+    ("Լ", 30),
+    ("Ի", 20),
+    ("Ժ", 10),
+    ("Թ", 9),
+    ("Ը", 8),
+    ("Է", 7),
+    ("Զ", 6),
+    ("Ե", 5),
+    ("Դ", 4),
+    ("Գ", 3),
+    ("Բ", 2),
+    ("Ա ", 1),
+]);
+
 /// Uppercase Armenian numbering.
 #[doc = include_str!("./previews/ArmenianUpper.md")]
 pub struct ArmenianUpper;
 
-impl<T> NominalSystem<T> for ArmenianUpper
-where
-    T: Nominal + TryFrom<u128>,
-{
-    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
-        AdditiveSet::new([
-            ("Ք", 9000),
-            ("Փ", 8000),
-            ("Ւ", 7000),
-            ("Ց", 6000),
-            ("Ր", 5000),
-            ("Տ", 4000),
-            ("Վ", 3000),
-            ("Ս", 2000),
-            ("Ռ", 1000),
-            ("Ջ", 900),
-            ("Պ", 800),
-            ("Չ", 700),
-            ("Ո", 600),
-            ("Շ", 500),
-            ("Ն", 400),
-            ("Յ", 300),
-            ("Մ", 200),
-            ("Ճ", 100),
-            ("Ղ", 90),
-            ("Ձ", 80),
-            ("Հ", 70),
-            ("Կ", 60),
-            ("Ծ", 50),
-            ("Խ", 40),
-            ("Լ", 30),
-            ("Ի", 20),
-            ("Ժ", 10),
-            ("Թ", 9),
-            ("Ը", 8),
-            ("Է", 7),
-            ("Զ", 6),
-            ("Ե", 5),
-            ("Դ", 4),
-            ("Գ", 3),
-            ("Բ", 2),
-            ("Ա ", 1),
-        ])
-        .try_format_nominal(nominal)
-    }
-}
+impl_additive_preset!(ArmenianUpper, ARMENIAN_UPPER);
+
+const ARMENIAN_LOWER: AdditiveSet<36> = AdditiveSet::new([
+    ("ք", 9000),
+    ("փ", 8000),
+    ("ւ", 7000),
+    ("ց", 6000),
+    ("ր", 5000),
+    ("տ", 4000),
+    ("վ", 3000),
+    ("ս", 2000),
+    ("ռ", 1000),
+    ("ջ", 900),
+    ("պ", 800),
+    ("չ", 700),
+    ("ո", 600),
+    ("շ", 500),
+    ("ն", 400),
+    ("յ", 300),
+    ("մ", 200),
+    ("ճ", 100),
+    ("ղ", 90),
+    ("ձ", 80),
+    ("հ", 70),
+    ("կ", 60),
+    ("ծ", 50),
+    ("խ", 40),
+    ("լ", 30),
+    ("ի", 20),
+    ("ժ", 10),
+    ("թ", 9),
+    ("ը", 8),
+    ("է", 7),
+    ("զ", 6),
+    ("ե", 5),
+    ("դ", 4),
+    ("գ", 3),
+    ("բ", 2),
+    ("ա", 1),
+]);
 
 /// Lowercase Armenian numbering.
 #[doc = include_str!("./previews/ArmenianLower.md")]
 pub struct ArmenianLower;
 
-impl<T> NominalSystem<T> for ArmenianLower
-where
-    T: Nominal + TryFrom<u128>,
-{
-    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
-        AdditiveSet::new([
-            ("ք", 9000),
-            ("փ", 8000),
-            ("ւ", 7000),
-            ("ց", 6000),
-            ("ր", 5000),
-            ("տ", 4000),
-            ("վ", 3000),
-            ("ս", 2000),
-            ("ռ", 1000),
-            ("ջ", 900),
-            ("պ", 800),
-            ("չ", 700),
-            ("ո", 600),
-            ("շ", 500),
-            ("ն", 400),
-            ("յ", 300),
-            ("մ", 200),
-            ("ճ", 100),
-            ("ղ", 90),
-            ("ձ", 80),
-            ("հ", 70),
-            ("կ", 60),
-            ("ծ", 50),
-            ("խ", 40),
-            ("լ", 30),
-            ("ի", 20),
-            ("ժ", 10),
-            ("թ", 9),
-            ("ը", 8),
-            ("է", 7),
-            ("զ", 6),
-            ("ե", 5),
-            ("դ", 4),
-            ("գ", 3),
-            ("բ", 2),
-            ("ա", 1),
-        ])
-        .try_format_nominal(nominal)
-    }
-}
+impl_additive_preset!(ArmenianLower, ARMENIAN_LOWER);
+
+const GEORGIAN: AdditiveSet<37> = AdditiveSet::new([
+    ("\u{10F5}", 10000),
+    ("\u{10F0}", 9000),
+    ("\u{10EF}", 8000),
+    ("\u{10F4}", 7000),
+    ("\u{10EE}", 6000),
+    ("\u{10ED}", 5000),
+    ("\u{10EC}", 4000),
+    ("\u{10EB}", 3000),
+    ("\u{10EA}", 2000),
+    ("\u{10E9}", 1000),
+    ("\u{10E8}", 900),
+    ("\u{10E7}", 800),
+    ("\u{10E6}", 700),
+    ("\u{10E5}", 600),
+    ("\u{10E4}", 500),
+    ("\u{10F3}", 400),
+    ("\u{10E2}", 300),
+    ("\u{10E1}", 200),
+    ("\u{10E0}", 100),
+    ("\u{10DF}", 90),
+    ("\u{10DE}", 80),
+    ("\u{10DD}", 70),
+    ("\u{10F2}", 60),
+    ("\u{10DC}", 50),
+    ("\u{10DB}", 40),
+    ("\u{10DA}", 30),
+    ("\u{10D9}", 20),
+    ("\u{10D8}", 10),
+    ("\u{10D7}", 9),
+    ("\u{10F1}", 8),
+    ("\u{10D6}", 7),
+    ("\u{10D5}", 6),
+    ("\u{10D4}", 5),
+    ("\u{10D3}", 4),
+    ("\u{10D2}", 3),
+    ("\u{10D1}", 2),
+    ("\u{10D0}", 1),
+]);
 
 /// Traditional Georgian numbering.
 #[doc = include_str!("./previews/Georgian.md")]
 pub struct Georgian;
 
-impl<T> NominalSystem<T> for Georgian
+impl_additive_preset!(Georgian, GEORGIAN);
+
+const GREEK_NUMERAL_LOWER: AdditiveSet<36> = AdditiveSet::new([
+    ("\u{375}θ", 9000),
+    ("\u{375}η", 8000),
+    ("\u{375}ζ", 7000),
+    ("\u{375}ϛ", 6000),
+    ("\u{375}ε", 5000),
+    ("\u{375}δ", 4000),
+    ("\u{375}γ", 3000),
+    ("\u{375}β", 2000),
+    ("\u{375}α", 1000),
+    ("ϡ", 900),
+    ("ω", 800),
+    ("ψ", 700),
+    ("χ", 600),
+    ("φ", 500),
+    ("υ", 400),
+    ("τ", 300),
+    ("σ", 200),
+    ("ρ", 100),
+    ("ϙ", 90),
+    ("π", 80),
+    ("ο", 70),
+    ("ξ", 60),
+    ("ν", 50),
+    ("μ", 40),
+    ("λ", 30),
+    ("κ", 20),
+    ("ι", 10),
+    ("θ", 9),
+    ("η", 8),
+    ("ζ", 7),
+    ("ϛ", 6),
+    ("ε", 5),
+    ("δ", 4),
+    ("γ", 3),
+    ("β", 2),
+    ("α", 1),
+]);
+
+const GREEK_NUMERAL_UPPER: AdditiveSet<36> = AdditiveSet::new([
+    ("\u{375}Θ", 9000),
+    ("\u{375}Η", 8000),
+    ("\u{375}Ζ", 7000),
+    ("\u{375}Ϛ", 6000),
+    ("\u{375}Ε", 5000),
+    ("\u{375}Δ", 4000),
+    ("\u{375}Γ", 3000),
+    ("\u{375}Β", 2000),
+    ("\u{375}Α", 1000),
+    ("Ϡ", 900),
+    ("Ω", 800),
+    ("Ψ", 700),
+    ("Χ", 600),
+    ("Φ", 500),
+    ("Υ", 400),
+    ("Τ", 300),
+    ("Σ", 200),
+    ("Ρ", 100),
+    ("Ϙ", 90),
+    ("Π", 80),
+    ("Ο", 70),
+    ("Ξ", 60),
+    ("Ν", 50),
+    ("Μ", 40),
+    ("Λ", 30),
+    ("Κ", 20),
+    ("Ι", 10),
+    ("Θ", 9),
+    ("Η", 8),
+    ("Ζ", 7),
+    ("Ϛ", 6),
+    ("Ε", 5),
+    ("Δ", 4),
+    ("Γ", 3),
+    ("Β", 2),
+    ("Α", 1),
+]);
+
+const KERAIA: char = '\u{374}';
+const LOWER_NUMERAL_SIGN: char = '\u{375}';
+const OVERLINE: char = '\u{305}';
+
+fn format_greek_numeral<T>(
+    set: &AdditiveSet<36>,
+    overline: bool,
+    nominal: T,
+) -> Result<NominalString, Error<T>>
 where
-    T: Nominal + TryFrom<u128>,
+    T: UnsignedInteger + TryFrom<u128>,
 {
-    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
-        AdditiveSet::new([
-            ("\u{10F5}", 10000),
-            ("\u{10F0}", 9000),
-            ("\u{10EF}", 8000),
-            ("\u{10F4}", 7000),
-            ("\u{10EE}", 6000),
-            ("\u{10ED}", 5000),
-            ("\u{10EC}", 4000),
-            ("\u{10EB}", 3000),
-            ("\u{10EA}", 2000),
-            ("\u{10E9}", 1000),
-            ("\u{10E8}", 900),
-            ("\u{10E7}", 800),
-            ("\u{10E6}", 700),
-            ("\u{10E5}", 600),
-            ("\u{10E4}", 500),
-            ("\u{10F3}", 400),
-            ("\u{10E2}", 300),
-            ("\u{10E1}", 200),
-            ("\u{10E0}", 100),
-            ("\u{10DF}", 90),
-            ("\u{10DE}", 80),
-            ("\u{10DD}", 70),
-            ("\u{10F2}", 60),
-            ("\u{10DC}", 50),
-            ("\u{10DB}", 40),
-            ("\u{10DA}", 30),
-            ("\u{10D9}", 20),
-            ("\u{10D8}", 10),
-            ("\u{10D7}", 9),
-            ("\u{10F1}", 8),
-            ("\u{10D6}", 7),
-            ("\u{10D5}", 6),
-            ("\u{10D4}", 5),
-            ("\u{10D3}", 4),
-            ("\u{10D2}", 3),
-            ("\u{10D1}", 2),
-            ("\u{10D0}", 1),
-        ])
-        .try_format_nominal(nominal)
+    if matches!(T::try_from(10_000_u128), Ok(limit) if nominal >= limit) {
+        return Err(Error::OutOfBounds(nominal));
     }
+    let letters = set.try_format_nominal(nominal.clone())?;
+
+    let mut formatted = NominalString::new();
+    if overline {
+        for ch in letters.chars() {
+            formatted.try_push(ch).with_nominal(nominal.clone())?;
+            if ch != LOWER_NUMERAL_SIGN {
+                formatted.try_push(OVERLINE).with_nominal(nominal.clone())?;
+            }
+        }
+    } else {
+        formatted
+            .try_push_str(&letters)
+            .with_nominal(nominal.clone())?;
+        // Multiples of 1,000 are already marked by the lower numeral sign.
+        if letters.chars().rev().nth(1) != Some(LOWER_NUMERAL_SIGN) {
+            formatted.try_push(KERAIA).with_nominal(nominal)?;
+        }
+    }
+    Ok(formatted)
 }
 
-/// Formal Japanese Kanji numbering.
-#[doc = include_str!("./previews/JapaneseFormal.md")]
-pub struct JapaneseFormal;
+fn parse_greek_numeral(set: &AdditiveSet<36>, overline: bool, nominal: &str) -> Option<u128> {
+    if overline {
+        let mut letters = NominalString::new();
+        for ch in nominal.chars().filter(|&ch| ch != OVERLINE) {
+            letters.try_push(ch).ok()?;
+        }
+        set.parse(&letters)
+    } else {
+        set.parse(nominal.strip_suffix(KERAIA).unwrap_or(nominal))
+    }
+}
 
-impl<T> NominalSystem<T> for JapaneseFormal
-where
-    T: Nominal + TryFrom<u128>,
-{
-    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
-        AdditiveSet::new([
-            ("\u{4E5D}\u{9621}", 9000),
-            ("\u{516B}\u{9621}", 8000),
-            ("\u{4E03}\u{9621}", 7000),
-            ("\u{516D}\u{9621}", 6000),
-            ("\u{4F0D}\u{9621}", 5000),
-            ("\u{56DB}\u{9621}", 4000),
-            ("\u{53C2}\u{9621}", 3000),
-            ("\u{5F10}\u{9621}", 2000),
-            ("\u{58F1}\u{9621}", 1000),
-            ("\u{4E5D}\u{767E}", 900),
-            ("\u{516B}\u{767E}", 800),
-            ("\u{4E03}\u{767E}", 700),
-            ("\u{516D}\u{767E}", 600),
-            ("\u{4F0D}\u{767E}", 500),
-            ("\u{56DB}\u{767E}", 400),
-            ("\u{53C2}\u{767E}", 300),
-            ("\u{5F10}\u{767E}", 200),
-            ("\u{58F1}\u{767E}", 100),
-            ("\u{4E5D}\u{62FE}", 90),
-            ("\u{516B}\u{62FE}", 80),
-            ("\u{4E03}\u{62FE}", 70),
-            ("\u{516D}\u{62FE}", 60),
-            ("\u{4F0D}\u{62FE}", 50),
-            ("\u{56DB}\u{62FE}", 40),
-            ("\u{53C2}\u{62FE}", 30),
-            ("\u{5F10}\u{62FE}", 20),
-            ("\u{58F1}\u{62FE}", 10),
-            ("\u{4E5D}", 9),
-            ("\u{516B}", 8),
-            ("\u{4E03}", 7),
-            ("\u{516D}", 6),
-            ("\u{4F0D}", 5),
-            ("\u{56DB}", 4),
-            ("\u{53C2}", 3),
-            ("\u{5F10}", 2),
-            ("\u{58F1}", 1),
-            ("\u{96F6}", 0),
-        ])
-        .try_format_nominal(nominal)
+macro_rules! impl_greek_numeral {
+    ($(#$doc:tt)* $name:ident, $set:ident) => {
+        $(#$doc)*
+        #[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+        pub struct $name {
+            overline: bool,
+        }
+
+        impl $name {
+            /// Returns a variation that draws a combining overline (U+0305)
+            /// above each letter instead of ending the nominal with a keraia,
+            /// as was customary in Byzantine manuscripts.
+            #[must_use]
+            pub const fn overline(mut self) -> Self {
+                self.overline = true;
+                self
+            }
+        }
+
+        impl<T> NominalSystem<T> for $name
+        where
+            T: UnsignedInteger + TryFrom<u128>,
+        {
+            fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+                format_greek_numeral(&$set, self.overline, nominal)
+            }
+        }
+
+        impl NominalSuccession for $name {
+            fn next_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+                step_parsed(
+                    nominal,
+                    true,
+                    |nominal| parse_greek_numeral(&$set, self.overline, nominal),
+                    |value| self.try_format_nominal(value),
+                )
+            }
+
+            fn prev_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+                step_parsed(
+                    nominal,
+                    false,
+                    |nominal| parse_greek_numeral(&$set, self.overline, nominal),
+                    |value| self.try_format_nominal(value),
+                )
+            }
+        }
+    };
+}
+
+impl_greek_numeral!(
+    /// Lowercase Greek alphabetic (Ionic) numerals.
+    ///
+    /// Numbers are written additively using the letters of the Greek alphabet
+    /// along with the archaic letters stigma (`ϛ`, 6), koppa (`ϙ`, 90), and
+    /// sampi (`ϡ`, 900), followed by a keraia (`ʹ`, U+0374). Thousands are
+    /// written by placing the lower numeral sign (`͵`, U+0375) before the
+    /// letters for 1 through 9. Numbers from 1 through 9,999 can be
+    /// formatted.
+    ///
+    /// ```rust
+    /// use nominals::{GreekNumeralLower, Nominal};
+    ///
+    /// assert_eq!(1_u32.to_nominal(&GreekNumeralLower::default()), "αʹ");
+    /// assert_eq!(666_u32.to_nominal(&GreekNumeralLower::default()), "χξϛʹ");
+    /// assert_eq!(1_000_u32.to_nominal(&GreekNumeralLower::default()), "͵α");
+    /// assert_eq!(1_999_u32.to_nominal(&GreekNumeralLower::default()), "͵αϡϙθʹ");
+    /// assert_eq!(
+    ///     12_u32.to_nominal(&GreekNumeralLower::default().overline()),
+    ///     "ι̅β̅"
+    /// );
+    /// ```
+    #[doc = include_str!("./previews/GreekNumeralLower.md")]
+    GreekNumeralLower,
+    GREEK_NUMERAL_LOWER
+);
+
+impl_greek_numeral!(
+    /// Uppercase Greek alphabetic (Ionic) numerals.
+    ///
+    /// Numbers are written additively using the letters of the Greek alphabet
+    /// along with the archaic letters stigma (`Ϛ`, 6), koppa (`Ϙ`, 90), and
+    /// sampi (`Ϡ`, 900), followed by a keraia (`ʹ`, U+0374). Thousands are
+    /// written by placing the lower numeral sign (`͵`, U+0375) before the
+    /// letters for 1 through 9. Numbers from 1 through 9,999 can be
+    /// formatted.
+    ///
+    /// ```rust
+    /// use nominals::{GreekNumeralUpper, Nominal};
+    ///
+    /// assert_eq!(2_024_u32.to_nominal(&GreekNumeralUpper::default()), "͵ΒΚΔʹ");
+    /// ```
+    #[doc = include_str!("./previews/GreekNumeralUpper.md")]
+    GreekNumeralUpper,
+    GREEK_NUMERAL_UPPER
+);
+
+const TITLO: char = '\u{483}';
+const THOUSANDS_SIGN: char = '\u{482}';
+
+/// The letters of an alphabetic Slavonic numeral system.
+struct SlavonicNumerals {
+    /// The letters for 1 through 9, 10 through 90, and 100 through 900.
+    letters: [char; 27],
+    /// The letter for 1,000, or `None` if thousands are written using the
+    /// thousands sign.
+    thousand: Option<char>,
+}
+
+impl SlavonicNumerals {
+    fn max(&self) -> u32 {
+        if self.thousand.is_some() {
+            1_999
+        } else {
+            999_999
+        }
+    }
+
+    /// Pushes the letters for `value`, which must be less than 1,000.
+    fn push_letters(
+        &self,
+        value: u32,
+        thousands: bool,
+        letters: &mut [(bool, char); 6],
+        len: &mut usize,
+    ) {
+        let mut push = |letter: char| {
+            letters[*len] = (thousands, letter);
+            *len += 1;
+        };
+        let hundreds = value / 100;
+        let tens = value / 10 % 10;
+        let ones = value % 10;
+        if hundreds > 0 {
+            push(self.letters[18 + hundreds as usize - 1]);
+        }
+        if tens == 1 && ones > 0 {
+            // 11 through 19 are written ones first, as they are read: аі
+            // ("one-on-ten").
+            push(self.letters[ones as usize - 1]);
+            push(self.letters[9]);
+        } else {
+            if tens > 0 {
+                push(self.letters[9 + tens as usize - 1]);
+            }
+            if ones > 0 {
+                push(self.letters[ones as usize - 1]);
+            }
+        }
+    }
+
+    fn format<T>(&self, nominal: T) -> Result<NominalString, Error<T>>
+    where
+        T: UnsignedInteger,
+    {
+        if nominal.is_zero() {
+            return Err(Error::NoZeroSymbol);
+        }
+        let value = match nominal
+            .clone()
+            .try_into()
+            .ok()
+            .and_then(|value: usize| u32::try_from(value).ok())
+        {
+            Some(value) if value <= self.max() => value,
+            _ => return Err(Error::OutOfBounds(nominal)),
+        };
+
+        let mut letters = [(false, ' '); 6];
+        let mut len = 0;
+        let thousands = value / 1_000;
+        if thousands > 0 {
+            if let Some(thousand) = self.thousand {
+                letters[0] = (false, thousand);
+                len = 1;
+            } else {
+                self.push_letters(thousands, true, &mut letters, &mut len);
+            }
+        }
+        self.push_letters(value % 1_000, false, &mut letters, &mut len);
+
+        // The titlo is placed over the second-to-last letter, or over the only
+        // letter.
+        let titlo = len.saturating_sub(2);
+        let mut formatted = NominalString::new();
+        for (index, &(thousands, letter)) in letters[..len].iter().enumerate() {
+            if thousands {
+                formatted
+                    .try_push(THOUSANDS_SIGN)
+                    .with_nominal(nominal.clone())?;
+            }
+            formatted.try_push(letter).with_nominal(nominal.clone())?;
+            if index == titlo {
+                formatted.try_push(TITLO).with_nominal(nominal.clone())?;
+            }
+        }
+        Ok(formatted)
+    }
+
+    fn parse(&self, nominal: &str) -> Option<u32> {
+        let mut value = 0_u32;
+        let mut thousands = false;
+        for ch in nominal.chars() {
+            if ch == TITLO {
+                continue;
+            }
+            if ch == THOUSANDS_SIGN && self.thousand.is_none() {
+                thousands = true;
+                continue;
+            }
+
+            let letter_value = if Some(ch) == self.thousand {
+                1_000
+            } else {
+                let index =
+                    u32::try_from(self.letters.iter().position(|&letter| letter == ch)?).ok()?;
+                10_u32.pow(index / 9) * (index % 9 + 1)
+            };
+            let multiplier = if thousands { 1_000 } else { 1 };
+            value = value.checked_add(letter_value * multiplier)?;
+            thousands = false;
+        }
+        (value > 0 && !thousands && value <= self.max()).then_some(value)
     }
 }
 
-/// Informal Japanese Kanji numbering.
-#[doc = include_str!("./previews/JapaneseInformal.md")]
-pub struct JapaneseInformal;
+macro_rules! impl_slavonic_numeral {
+    ($(#$doc:tt)* $name:ident, $set:ident) => {
+        $(#$doc)*
+        #[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+        pub struct $name;
 
-impl<T> NominalSystem<T> for JapaneseInformal
-where
-    T: Nominal + TryFrom<u128>,
-{
-    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
-        AdditiveSet::new([
-            ("\u{4E5D}\u{5343}", 9000),
-            ("\u{516B}\u{5343}", 8000),
-            ("\u{4E03}\u{5343}", 7000),
-            ("\u{516D}\u{5343}", 6000),
-            ("\u{4E94}\u{5343}", 5000),
-            ("\u{56DB}\u{5343}", 4000),
-            ("\u{4E09}\u{5343}", 3000),
-            ("\u{4E8C}\u{5343}", 2000),
-            ("\u{5343}", 1000),
-            ("\u{4E5D}\u{767E}", 900),
-            ("\u{516B}\u{767E}", 800),
-            ("\u{4E03}\u{767E}", 700),
-            ("\u{516D}\u{767E}", 600),
-            ("\u{4E94}\u{767E}", 500),
-            ("\u{56DB}\u{767E}", 400),
-            ("\u{4E09}\u{767E}", 300),
-            ("\u{4E8C}\u{767E}", 200),
-            ("\u{767E}", 100),
-            ("\u{4E5D}\u{5341}", 90),
-            ("\u{516B}\u{5341}", 80),
-            ("\u{4E03}\u{5341}", 70),
-            ("\u{516D}\u{5341}", 60),
-            ("\u{4E94}\u{5341}", 50),
-            ("\u{56DB}\u{5341}", 40),
-            ("\u{4E09}\u{5341}", 30),
-            ("\u{4E8C}\u{5341}", 20),
-            ("\u{5341}", 10),
-            ("\u{4E5D}", 9),
-            ("\u{516B}", 8),
-            ("\u{4E03}", 7),
-            ("\u{516D}", 6),
-            ("\u{4E94}", 5),
-            ("\u{56DB}", 4),
-            ("\u{4E09}", 3),
-            ("\u{4E8C}", 2),
-            ("\u{4E00}", 1),
-            ("\u{3007}", 0),
-        ])
-        .try_format_nominal(nominal)
+        impl $name {
+            /// Returns the value of `nominal`, or `None` if `nominal` is not a
+            /// number in this system.
+            ///
+            /// The titlo is optional, and letters may appear in any order, so
+            /// numbers transcribed from manuscripts can be read even when they
+            /// are not written exactly as this system formats them.
+            #[must_use]
+            pub fn parse(&self, nominal: &str) -> Option<u32> {
+                $set.parse(nominal)
+            }
+        }
+
+        impl<T> NominalSystem<T> for $name
+        where
+            T: UnsignedInteger,
+        {
+            fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+                $set.format(nominal)
+            }
+        }
+
+        impl NominalSuccession for $name {
+            fn next_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+                step_parsed(
+                    nominal,
+                    true,
+                    |nominal| $set.parse(nominal).map(u128::from),
+                    |value| self.try_format_nominal(value),
+                )
+            }
+
+            fn prev_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+                step_parsed(
+                    nominal,
+                    false,
+                    |nominal| $set.parse(nominal).map(u128::from),
+                    |value| self.try_format_nominal(value),
+                )
+            }
+        }
+    };
+}
+
+const CYRILLIC: SlavonicNumerals = SlavonicNumerals {
+    letters: [
+        'а', 'в', 'г', 'д', 'є', 'ѕ', 'з', 'и', 'ѳ', // 1–9
+        'і', 'к', 'л', 'м', 'н', 'ѯ', 'о', 'п', 'ч', // 10–90
+        'р', 'с', 'т', 'ѵ', 'ф', 'х', 'ѱ', 'ѿ', 'ц', // 100–900
+    ],
+    thousand: None,
+};
+
+impl_slavonic_numeral!(
+    /// Church Slavonic Cyrillic numerals.
+    ///
+    /// Numbers are written using the letters assigned to 1 through 900, with a
+    /// titlo (U+0483) above the second-to-last letter. The numbers 11 through
+    /// 19 are written with the ones before the ten (`аі`), and thousands are
+    /// written with the thousands sign `҂` (U+0482) before each letter. Numbers
+    /// from 1 through 999,999 can be formatted.
+    ///
+    /// ```rust
+    /// use nominals::{CyrillicNumeral, Nominal};
+    ///
+    /// assert_eq!(1_u32.to_nominal(&CyrillicNumeral), "а҃");
+    /// assert_eq!(11_u32.to_nominal(&CyrillicNumeral), "а҃і");
+    /// assert_eq!(21_u32.to_nominal(&CyrillicNumeral), "к҃а");
+    /// assert_eq!(1_988_u32.to_nominal(&CyrillicNumeral), "҂ацп҃и");
+    /// assert_eq!(CyrillicNumeral.parse("҂ацп҃и"), Some(1_988));
+    /// ```
+    #[doc = include_str!("./previews/CyrillicNumeral.md")]
+    CyrillicNumeral,
+    CYRILLIC
+);
+
+const GLAGOLITIC: SlavonicNumerals = SlavonicNumerals {
+    letters: [
+        'ⰰ', 'ⰱ', 'ⰲ', 'ⰳ', 'ⰴ', 'ⰵ', 'ⰶ', 'ⰷ', 'ⰸ', // 1–9
+        'ⰹ', 'ⰻ', 'ⰼ', 'ⰽ', 'ⰾ', 'ⰿ', 'ⱀ', 'ⱁ', 'ⱂ', // 10–90
+        'ⱃ', 'ⱄ', 'ⱅ', 'ⱆ', 'ⱇ', 'ⱈ', 'ⱉ', 'ⱋ', 'ⱌ', // 100–900
+    ],
+    thousand: Some('ⱍ'),
+};
+
+impl_slavonic_numeral!(
+    /// Glagolitic numerals.
+    ///
+    /// Each letter of the Glagolitic alphabet is assigned a value in order,
+    /// from `ⰰ` (1) through `ⱍ` (1,000). Like [`CyrillicNumeral`], a titlo
+    /// (U+0483) is placed above the second-to-last letter and the numbers 11
+    /// through 19 are written with the ones before the ten. Numbers from 1
+    /// through 1,999 can be formatted.
+    ///
+    /// ```rust
+    /// use nominals::{GlagoliticNumeral, Nominal};
+    ///
+    /// assert_eq!(3_u32.to_nominal(&GlagoliticNumeral), "ⰲ҃");
+    /// assert_eq!(15_u32.to_nominal(&GlagoliticNumeral), "ⰴ҃ⰹ");
+    /// assert_eq!(1_000_u32.to_nominal(&GlagoliticNumeral), "ⱍ҃");
+    /// ```
+    #[doc = include_str!("./previews/GlagoliticNumeral.md")]
+    GlagoliticNumeral,
+    GLAGOLITIC
+);
+
+const ABJAD_MASHRIQI: AdditiveSet<27> = AdditiveSet::new([
+    ("ظ", 900),
+    ("ض", 800),
+    ("ذ", 700),
+    ("خ", 600),
+    ("ث", 500),
+    ("ت", 400),
+    ("ش", 300),
+    ("ر", 200),
+    ("ق", 100),
+    ("ص", 90),
+    ("ف", 80),
+    ("ع", 70),
+    ("س", 60),
+    ("ن", 50),
+    ("م", 40),
+    ("ل", 30),
+    ("ك", 20),
+    ("ي", 10),
+    ("ط", 9),
+    ("ح", 8),
+    ("ز", 7),
+    ("و", 6),
+    ("ه", 5),
+    ("د", 4),
+    ("ج", 3),
+    ("ب", 2),
+    ("أ", 1),
+]);
+
+const ABJAD_MAGHREBI: AdditiveSet<27> = AdditiveSet::new([
+    ("غ", 900),
+    ("ظ", 800),
+    ("ذ", 700),
+    ("خ", 600),
+    ("ث", 500),
+    ("ت", 400),
+    ("س", 300),
+    ("ر", 200),
+    ("ق", 100),
+    ("ض", 90),
+    ("ف", 80),
+    ("ع", 70),
+    ("ص", 60),
+    ("ن", 50),
+    ("م", 40),
+    ("ل", 30),
+    ("ك", 20),
+    ("ي", 10),
+    ("ط", 9),
+    ("ح", 8),
+    ("ز", 7),
+    ("و", 6),
+    ("ه", 5),
+    ("د", 4),
+    ("ج", 3),
+    ("ب", 2),
+    ("أ", 1),
+]);
+
+const ABJAD_PERSIAN: AdditiveSet<27> = AdditiveSet::new([
+    ("ظ", 900),
+    ("ض", 800),
+    ("ذ", 700),
+    ("خ", 600),
+    ("ث", 500),
+    ("ت", 400),
+    ("ش", 300),
+    ("ر", 200),
+    ("ق", 100),
+    ("ص", 90),
+    ("ف", 80),
+    ("ع", 70),
+    ("س", 60),
+    ("ن", 50),
+    ("م", 40),
+    ("ل", 30),
+    ("ک", 20),
+    ("ی", 10),
+    ("ط", 9),
+    ("ح", 8),
+    ("ز", 7),
+    ("و", 6),
+    ("ه", 5),
+    ("د", 4),
+    ("ج", 3),
+    ("ب", 2),
+    ("ا", 1),
+]);
+
+const RIGHT_TO_LEFT_ISOLATE: char = '\u{2067}';
+const POP_DIRECTIONAL_ISOLATE: char = '\u{2069}';
+
+/// The letters and options of an Abjad numeral system.
+#[derive(Clone, Copy)]
+struct Abjad {
+    units: &'static AdditiveSet<27>,
+    thousand: char,
+    isolated: bool,
+}
+
+impl Abjad {
+    fn format<T>(self, nominal: T) -> Result<NominalString, Error<T>>
+    where
+        T: UnsignedInteger,
+    {
+        if nominal.is_zero() {
+            return Err(Error::NoZeroSymbol);
+        }
+
+        let mut formatted = NominalString::new();
+        if self.isolated {
+            formatted
+                .try_push(RIGHT_TO_LEFT_ISOLATE)
+                .with_nominal(nominal.clone())?;
+        }
+        self.push(nominal.clone(), &mut formatted)
+            .with_nominal(nominal.clone())?;
+        if self.isolated {
+            formatted
+                .try_push(POP_DIRECTIONAL_ISOLATE)
+                .with_nominal(nominal)?;
+        }
+        Ok(formatted)
+    }
+
+    /// Pushes the letters for `value`, which must not be zero.
+    ///
+    /// Multiples of 1,000 are written by placing the number of thousands
+    /// before the letter for 1,000, omitting the count when it is one.
+    fn push<T>(self, value: T, formatted: &mut NominalString) -> Result<(), OutOfMemoryError>
+    where
+        T: UnsignedInteger,
+    {
+        let thousand = T::try_from(1_000)
+            .ok()
+            .filter(|thousand| value >= *thousand);
+        let remainder = if let Some(thousand) = thousand {
+            let thousands = value.clone() / thousand.clone();
+            if thousands > T::from(1) {
+                self.push(thousands, formatted)?;
+            }
+            formatted.try_push(self.thousand)?;
+            value % thousand
+        } else {
+            value
+        };
+
+        if !remainder.is_zero() {
+            let letters = self
+                .units
+                .try_format_nominal(remainder.as_usize())
+                .map_err(|_| OutOfMemoryError)?;
+            formatted.try_push_str(&letters)?;
+        }
+        Ok(())
+    }
+
+    fn parse(self, nominal: &str) -> Option<u128> {
+        let nominal = if self.isolated {
+            nominal
+                .strip_prefix(RIGHT_TO_LEFT_ISOLATE)?
+                .strip_suffix(POP_DIRECTIONAL_ISOLATE)?
+        } else {
+            nominal
+        };
+        self.parse_letters(nominal)
+    }
+
+    fn parse_letters(self, nominal: &str) -> Option<u128> {
+        let Some(index) = nominal.rfind(self.thousand) else {
+            return self.units.parse(nominal);
+        };
+        let thousands = &nominal[..index];
+        let remainder = &nominal[index + self.thousand.len_utf8()..];
+        let thousands = if thousands.is_empty() {
+            1
+        } else {
+            self.parse_letters(thousands)?
+        };
+        let remainder = if remainder.is_empty() {
+            0
+        } else {
+            self.units.parse(remainder)?
+        };
+        thousands.checked_mul(1_000)?.checked_add(remainder)
+    }
+
+    fn step(self, nominal: &str, forward: bool) -> Result<NominalString, SuccessionError> {
+        step_parsed(
+            nominal,
+            forward,
+            |nominal| self.parse(nominal),
+            |value| self.format(value),
+        )
     }
 }
 
-/// Korean Hangeul/Hangul numbering.
+/// Arabic Abjad numerals.
+///
+/// Numbers are written additively using the letters of the Arabic alphabet in
+/// their traditional Abjad order, from `أ` (1) through `غ` (1,000), using the
+/// Mashriqi (Eastern) values by default. Thousands are written by placing the
+/// number of thousands before `غ`: 2,000 is `بغ`, and 1,000,000 is `غغ`.
 ///
-/// [Why Hangeul and not Hangul?](https://github.com/khonsulabs/nominals/issues/6)
-#[doc = include_str!("./previews/HangeulFormal.md")]
-pub struct HangeulFormal;
+/// Nominals are written in logical order and are displayed from right to left.
+/// When nominals are placed within left-to-right text, such as before a `.`
+/// in an [`Outline`](crate::Outline), [`AbjadArabic::isolated`] wraps each
+/// nominal in directional isolates so that the surrounding text is not
+/// reordered.
+///
+/// ```rust
+/// use nominals::{AbjadArabic, Nominal};
+///
+/// assert_eq!(1_u32.to_nominal(&AbjadArabic::default()), "أ");
+/// assert_eq!(12_u32.to_nominal(&AbjadArabic::default()), "يب");
+/// assert_eq!(1_445_u32.to_nominal(&AbjadArabic::default()), "غتمه");
+/// assert_eq!(60_u32.to_nominal(&AbjadArabic::default().maghrebi()), "ص");
+/// ```
+#[doc = include_str!("./previews/AbjadArabic.md")]
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct AbjadArabic {
+    maghrebi: bool,
+    isolated: bool,
+}
 
-impl<T> NominalSystem<T> for HangeulFormal
+impl AbjadArabic {
+    /// Returns a variation that uses the Maghrebi (Western) values, where
+    /// `ص` is 60, `ض` is 90, `س` is 300, `ظ` is 800, `غ` is 900, and `ش` is
+    /// 1,000.
+    #[must_use]
+    pub const fn maghrebi(mut self) -> Self {
+        self.maghrebi = true;
+        self
+    }
+
+    /// Returns a variation that surrounds each nominal with a right-to-left
+    /// isolate (U+2067) and a pop directional isolate (U+2069).
+    #[must_use]
+    pub const fn isolated(mut self) -> Self {
+        self.isolated = true;
+        self
+    }
+
+    const fn abjad(self) -> Abjad {
+        if self.maghrebi {
+            Abjad {
+                units: &ABJAD_MAGHREBI,
+                thousand: 'ش',
+                isolated: self.isolated,
+            }
+        } else {
+            Abjad {
+                units: &ABJAD_MASHRIQI,
+                thousand: 'غ',
+                isolated: self.isolated,
+            }
+        }
+    }
+}
+
+impl<T> NominalSystem<T> for AbjadArabic
 where
-    T: Nominal + TryFrom<u128>,
+    T: UnsignedInteger,
 {
     fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
-        AdditiveSet::new([
-            ("\u{AD6C}\u{CC9C}", 9000),
-            ("\u{D314}\u{CC9C}", 8000),
-            ("\u{CE60}\u{CC9C}", 7000),
-            ("\u{C721}\u{CC9C}", 6000),
-            ("\u{C624}\u{CC9C}", 5000),
-            ("\u{C0AC}\u{CC9C}", 4000),
-            ("\u{C0BC}\u{CC9C}", 3000),
-            ("\u{C774}\u{CC9C}", 2000),
-            ("\u{C77C}\u{CC9C}", 1000),
-            ("\u{AD6C}\u{BC31}", 900),
-            ("\u{D314}\u{BC31}", 800),
-            ("\u{CE60}\u{BC31}", 700),
-            ("\u{C721}\u{BC31}", 600),
-            ("\u{C624}\u{BC31}", 500),
-            ("\u{C0AC}\u{BC31}", 400),
-            ("\u{C0BC}\u{BC31}", 300),
-            ("\u{C774}\u{BC31}", 200),
-            ("\u{C77C}\u{BC31}", 100),
-            ("\u{AD6C}\u{C2ED}", 90),
-            ("\u{D314}\u{C2ED}", 80),
-            ("\u{CE60}\u{C2ED}", 70),
-            ("\u{C721}\u{C2ED}", 60),
-            ("\u{C624}\u{C2ED}", 50),
-            ("\u{C0AC}\u{C2ED}", 40),
-            ("\u{C0BC}\u{C2ED}", 30),
-            ("\u{C774}\u{C2ED}", 20),
-            ("\u{C77C}\u{C2ED}", 10),
-            ("\u{AD6C}", 9),
-            ("\u{D314}", 8),
-            ("\u{CE60}", 7),
-            ("\u{C721}", 6),
-            ("\u{C624}", 5),
-            ("\u{C0AC}", 4),
-            ("\u{C0BC}", 3),
-            ("\u{C774}", 2),
-            ("\u{C77C}", 1),
-            ("\u{C601}", 0),
-        ])
-        .try_format_nominal(nominal)
+        self.abjad().format(nominal)
     }
 }
 
-/// Informal Korean Hanja numbering.
-#[doc = include_str!("./previews/HanjaInformal.md")]
-pub struct HanjaInformal;
+impl NominalSuccession for AbjadArabic {
+    fn next_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        self.abjad().step(nominal, true)
+    }
 
-impl<T> NominalSystem<T> for HanjaInformal
-where
-    T: Nominal + TryFrom<u128>,
-{
-    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
-        AdditiveSet::new([
-            ("\u{4E5D}\u{5343}", 9000),
-            ("\u{516B}\u{5343}", 8000),
-            ("\u{4E03}\u{5343}", 7000),
-            ("\u{516D}\u{5343}", 6000),
-            ("\u{4E94}\u{5343}", 5000),
-            ("\u{56DB}\u{5343}", 4000),
-            ("\u{4E09}\u{5343}", 3000),
-            ("\u{4E8C}\u{5343}", 2000),
-            ("\u{5343}", 1000),
-            ("\u{4E5D}\u{767E}", 900),
-            ("\u{516B}\u{767E}", 800),
-            ("\u{4E03}\u{767E}", 700),
-            ("\u{516D}\u{767E}", 600),
-            ("\u{4E94}\u{767E}", 500),
-            ("\u{56DB}\u{767E}", 400),
-            ("\u{4E09}\u{767E}", 300),
-            ("\u{4E8C}\u{767E}", 200),
-            ("\u{767E}", 100),
-            ("\u{4E5D}\u{5341}", 90),
-            ("\u{516B}\u{5341}", 80),
-            ("\u{4E03}\u{5341}", 70),
-            ("\u{516D}\u{5341}", 60),
-            ("\u{4E94}\u{5341}", 50),
-            ("\u{56DB}\u{5341}", 40),
-            ("\u{4E09}\u{5341}", 30),
-            ("\u{4E8C}\u{5341}", 20),
-            ("\u{5341}", 10),
-            ("\u{4E5D}", 9),
-            ("\u{516B}", 8),
-            ("\u{4E03}", 7),
-            ("\u{516D}", 6),
-            ("\u{4E94}", 5),
-            ("\u{56DB}", 4),
-            ("\u{4E09}", 3),
-            ("\u{4E8C}", 2),
-            ("\u{4E00}", 1),
-            ("\u{96F6}", 0),
-        ])
-        .try_format_nominal(nominal)
+    fn prev_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        self.abjad().step(nominal, false)
     }
 }
 
-/// Formal Korean Hanja numbering.
-#[doc = include_str!("./previews/HanjaFormal.md")]
-pub struct HanjaFormal;
+/// Persian Abjad numerals.
+///
+/// This system uses the same Mashriqi values as [`AbjadArabic`], written
+/// using the Persian forms of the letters: `ا` (1), `ی` (10), and `ک` (20).
+///
+/// ```rust
+/// use nominals::{AbjadPersian, Nominal};
+///
+/// assert_eq!(1_u32.to_nominal(&AbjadPersian::default()), "ا");
+/// assert_eq!(21_u32.to_nominal(&AbjadPersian::default()), "کا");
+/// assert_eq!(2_000_u32.to_nominal(&AbjadPersian::default()), "بغ");
+/// ```
+#[doc = include_str!("./previews/AbjadPersian.md")]
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct AbjadPersian {
+    isolated: bool,
+}
+
+impl AbjadPersian {
+    /// Returns a variation that surrounds each nominal with a right-to-left
+    /// isolate (U+2067) and a pop directional isolate (U+2069).
+    #[must_use]
+    pub const fn isolated(mut self) -> Self {
+        self.isolated = true;
+        self
+    }
+
+    const fn abjad(self) -> Abjad {
+        Abjad {
+            units: &ABJAD_PERSIAN,
+            thousand: 'غ',
+            isolated: self.isolated,
+        }
+    }
+}
 
-impl<T> NominalSystem<T> for HanjaFormal
+impl<T> NominalSystem<T> for AbjadPersian
 where
-    T: Nominal + TryFrom<u128>,
+    T: UnsignedInteger,
 {
     fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
-        AdditiveSet::new([
-            ("\u{4E5D}\u{4EDF}", 9000),
-            ("\u{516B}\u{4EDF}", 8000),
-            ("\u{4E03}\u{4EDF}", 7000),
-            ("\u{516D}\u{4EDF}", 6000),
-            ("\u{4E94}\u{4EDF}", 5000),
-            ("\u{56DB}\u{4EDF}", 4000),
-            ("\u{53C3}\u{4EDF}", 3000),
-            ("\u{8CB3}\u{4EDF}", 2000),
-            ("\u{58F9}\u{4EDF}", 1000),
-            ("\u{4E5D}\u{767E}", 900),
-            ("\u{516B}\u{767E}", 800),
-            ("\u{4E03}\u{767E}", 700),
-            ("\u{516D}\u{767E}", 600),
-            ("\u{4E94}\u{767E}", 500),
-            ("\u{56DB}\u{767E}", 400),
-            ("\u{53C3}\u{767E}", 300),
-            ("\u{8CB3}\u{767E}", 200),
-            ("\u{58F9}\u{767E}", 100),
-            ("\u{4E5D}\u{62FE}", 90),
-            ("\u{516B}\u{62FE}", 80),
-            ("\u{4E03}\u{62FE}", 70),
-            ("\u{516D}\u{62FE}", 60),
-            ("\u{4E94}\u{62FE}", 50),
-            ("\u{56DB}\u{62FE}", 40),
-            ("\u{53C3}\u{62FE}", 30),
-            ("\u{8CB3}\u{62FE}", 20),
-            ("\u{58F9}\u{62FE}", 10),
-            ("\u{4E5D}", 9),
-            ("\u{516B}", 8),
-            ("\u{4E03}", 7),
-            ("\u{516D}", 6),
-            ("\u{4E94}", 5),
-            ("\u{56DB}", 4),
-            ("\u{53C3}", 3),
-            ("\u{8CB3}", 2),
-            ("\u{58F9}", 1),
-            ("\u{96F6}", 0),
-        ])
-        .try_format_nominal(nominal)
+        self.abjad().format(nominal)
+    }
+}
+
+impl NominalSuccession for AbjadPersian {
+    fn next_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        self.abjad().step(nominal, true)
+    }
+
+    fn prev_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        self.abjad().step(nominal, false)
+    }
+}
+
+#[test]
+fn succession() {
+    for n in 1_u32..10_000 {
+        let formatted = ArmenianLower.format_nominal(n);
+        let next = ArmenianLower.format_nominal(n + 1);
+        assert_eq!(ArmenianLower.next_nominal(&formatted).unwrap(), next, "{n}");
+        assert_eq!(ArmenianLower.prev_nominal(&next).unwrap(), formatted, "{n}");
+    }
+    assert_eq!(
+        ArmenianLower.prev_nominal("ա"),
+        Err(SuccessionError::NoPredecessor)
+    );
+    assert_eq!(
+        ArmenianLower.next_nominal("աա"),
+        Err(SuccessionError::InvalidNominal)
+    );
+
+    let with_zero = AdditiveSet::new([("X", 10), ("I", 1), ("-", 0)]);
+    assert_eq!(with_zero.prev_nominal("I").unwrap(), "-");
+    assert_eq!(with_zero.next_nominal("-").unwrap(), "I");
+    assert_eq!(with_zero.next_nominal("XIIIIIIIII").unwrap(), "XX");
+    assert_eq!(
+        with_zero.prev_nominal("-"),
+        Err(SuccessionError::NoPredecessor)
+    );
+}
+
+#[test]
+fn greek_numerals() {
+    use crate::Nominal;
+
+    let lower = GreekNumeralLower::default();
+    assert_eq!(6_u32.to_nominal(&lower), "ϛ\u{374}");
+    assert_eq!(11_u32.to_nominal(&lower), "ια\u{374}");
+    assert_eq!(99_u32.to_nominal(&lower), "ϙθ\u{374}");
+    assert_eq!(900_u32.to_nominal(&lower), "ϡ\u{374}");
+    assert_eq!(2_000_u32.to_nominal(&lower), "͵β");
+    assert_eq!(9_999_u32.to_nominal(&lower), "͵θϡϙθ\u{374}");
+    assert_eq!(0_u32.try_to_nominal(&lower), Err(Error::NoZeroSymbol));
+    assert_eq!(
+        10_000_u32.try_to_nominal(&lower),
+        Err(Error::OutOfBounds(10_000))
+    );
+    assert_eq!(255_u8.to_nominal(&lower), "σνε\u{374}");
+
+    let overline = GreekNumeralUpper::default().overline();
+    assert_eq!(1_001_u32.to_nominal(&overline), "͵Α̅Α̅");
+
+    for n in 1_u32..9_999 {
+        let formatted = lower.format_nominal(n);
+        let next = lower.format_nominal(n + 1);
+        assert_eq!(lower.next_nominal(&formatted).unwrap(), next, "{n}");
+        assert_eq!(lower.prev_nominal(&next).unwrap(), formatted, "{n}");
+        let formatted = overline.format_nominal(n);
+        assert_eq!(
+            overline.next_nominal(&formatted).unwrap(),
+            overline.format_nominal(n + 1),
+            "{n}"
+        );
     }
+    assert_eq!(
+        lower.next_nominal("͵θϡϙθ\u{374}"),
+        Err(SuccessionError::NoSuccessor)
+    );
+    assert_eq!(
+        lower.next_nominal("αα"),
+        Err(SuccessionError::InvalidNominal)
+    );
+}
+
+#[test]
+fn slavonic_numerals() {
+    use crate::Nominal;
+
+    assert_eq!(10_u32.to_nominal(&CyrillicNumeral), "і\u{483}");
+    assert_eq!(19_u32.to_nominal(&CyrillicNumeral), "ѳ\u{483}і");
+    assert_eq!(90_u32.to_nominal(&CyrillicNumeral), "ч\u{483}");
+    assert_eq!(111_u32.to_nominal(&CyrillicNumeral), "ра\u{483}і");
+    assert_eq!(1_000_u32.to_nominal(&CyrillicNumeral), "҂а\u{483}");
+    assert_eq!(1_001_u32.to_nominal(&CyrillicNumeral), "҂а\u{483}а");
+    assert_eq!(12_000_u32.to_nominal(&CyrillicNumeral), "҂в\u{483}҂і");
+    assert_eq!(999_999_u32.to_nominal(&CyrillicNumeral), "҂ц҂ч҂ѳцч\u{483}ѳ");
+    assert_eq!(
+        1_000_000_u32.try_to_nominal(&CyrillicNumeral),
+        Err(Error::OutOfBounds(1_000_000))
+    );
+    assert_eq!(
+        0_u32.try_to_nominal(&CyrillicNumeral),
+        Err(Error::NoZeroSymbol)
+    );
+
+    assert_eq!(CyrillicNumeral.parse("аі"), Some(11));
+    assert_eq!(CyrillicNumeral.parse("іа"), Some(11));
+    assert_eq!(CyrillicNumeral.parse("҂в҂і"), Some(12_000));
+    assert_eq!(CyrillicNumeral.parse("҂"), None);
+    assert_eq!(CyrillicNumeral.parse("ab"), None);
+
+    assert_eq!(1_999_u32.to_nominal(&GlagoliticNumeral), "ⱍⱌⱂ\u{483}ⰸ");
+    assert_eq!(
+        2_000_u32.try_to_nominal(&GlagoliticNumeral),
+        Err(Error::OutOfBounds(2_000))
+    );
+    assert_eq!(GlagoliticNumeral.parse("ⱍⱌⱂ\u{483}ⰸ"), Some(1_999));
+
+    for n in 1_u32..20_000 {
+        let formatted = CyrillicNumeral.format_nominal(n);
+        let next = CyrillicNumeral.format_nominal(n + 1);
+        assert_eq!(CyrillicNumeral.parse(&formatted), Some(n));
+        assert_eq!(
+            CyrillicNumeral.next_nominal(&formatted).unwrap(),
+            next,
+            "{n}"
+        );
+        assert_eq!(
+            CyrillicNumeral.prev_nominal(&next).unwrap(),
+            formatted,
+            "{n}"
+        );
+    }
+    for n in 1_u32..1_999 {
+        let formatted = GlagoliticNumeral.format_nominal(n);
+        assert_eq!(
+            GlagoliticNumeral.next_nominal(&formatted).unwrap(),
+            GlagoliticNumeral.format_nominal(n + 1),
+            "{n}"
+        );
+    }
+    assert_eq!(
+        CyrillicNumeral.next_nominal("а\u{483}і\u{483}"),
+        Err(SuccessionError::InvalidNominal)
+    );
+    assert_eq!(
+        GlagoliticNumeral.next_nominal("ⱍⱌⱂ\u{483}ⰸ"),
+        Err(SuccessionError::NoSuccessor)
+    );
+}
+
+#[test]
+fn abjad() {
+    use crate::Nominal;
+
+    let arabic = AbjadArabic::default();
+    assert_eq!(10_u32.to_nominal(&arabic), "ي");
+    assert_eq!(999_u32.to_nominal(&arabic), "ظصط");
+    assert_eq!(1_000_u32.to_nominal(&arabic), "غ");
+    assert_eq!(1_001_u32.to_nominal(&arabic), "غأ");
+    assert_eq!(11_000_u32.to_nominal(&arabic), "يأغ");
+    assert_eq!(1_000_000_u32.to_nominal(&arabic), "غغ");
+    assert_eq!(1_001_000_u32.to_nominal(&arabic), "غأغ");
+    assert_eq!(0_u32.try_to_nominal(&arabic), Err(Error::NoZeroSymbol));
+    assert_eq!(255_u8.to_nominal(&arabic), "رنه");
+
+    let maghrebi = arabic.maghrebi();
+    assert_eq!(90_u32.to_nominal(&maghrebi), "ض");
+    assert_eq!(300_u32.to_nominal(&maghrebi), "س");
+    assert_eq!(900_u32.to_nominal(&maghrebi), "غ");
+    assert_eq!(1_900_u32.to_nominal(&maghrebi), "شغ");
+    assert_eq!(2_000_u32.to_nominal(&maghrebi), "بش");
+
+    assert_eq!(10_u32.to_nominal(&AbjadPersian::default()), "ی");
+    assert_eq!(5_u32.to_nominal(&arabic.isolated()), "\u{2067}ه\u{2069}");
+
+    for system in [arabic, maghrebi, arabic.isolated()] {
+        for n in 1_u32..12_000 {
+            let formatted = system.format_nominal(n);
+            let next = system.format_nominal(n + 1);
+            assert_eq!(system.next_nominal(&formatted).unwrap(), next, "{n}");
+            assert_eq!(system.prev_nominal(&next).unwrap(), formatted, "{n}");
+        }
+    }
+    assert_eq!(AbjadPersian::default().next_nominal("غغ").unwrap(), "غغا");
+    assert_eq!(
+        arabic.prev_nominal("أ"),
+        Err(SuccessionError::NoPredecessor)
+    );
+    assert_eq!(
+        arabic.next_nominal("أأ"),
+        Err(SuccessionError::InvalidNominal)
+    );
+    assert_eq!(
+        arabic.isolated().next_nominal("أ"),
+        Err(SuccessionError::InvalidNominal)
+    );
 }
@@ -0,0 +1,114 @@
+use num_bigint::BigUint;
+
+use crate::{IntegerDivision, Nominal, UnsignedInteger};
+
+impl IntegerDivision for BigUint {}
+
+impl UnsignedInteger for BigUint {
+    fn is_zero(&self) -> bool {
+        self.bits() == 0
+    }
+
+    fn as_usize(&self) -> usize {
+        usize::try_from(self).unwrap_or(usize::MAX)
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+}
+
+impl Nominal<BigUint> for &BigUint {
+    fn into_integer(self) -> BigUint {
+        self.clone()
+    }
+}
+
+#[cfg(test)]
+fn ten_to_the(exponent: u32) -> BigUint {
+    BigUint::from(10_u8).pow(exponent)
+}
+
+#[test]
+fn digits() {
+    use crate::{Decimal, DigitCollection, LetterLower};
+
+    let big = BigUint::from(u128::MAX) + 1_u8;
+    assert_eq!(
+        big.to_nominal(&Decimal),
+        "340282366920938463463374607431768211456"
+    );
+    assert_eq!(
+        ten_to_the(60).to_nominal(&Decimal),
+        "1000000000000000000000000000000000000000000000000000000000000"
+    );
+    assert_eq!(BigUint::from(0_u8).to_nominal(&Decimal), "0");
+    assert_eq!(BigUint::from(26_u8).to_nominal(&LetterLower), "aa");
+    assert_eq!(
+        (&BigUint::from(27_u8)).to_nominal(&LetterLower.one_based()),
+        "aa"
+    );
+}
+
+#[test]
+fn additive() {
+    use crate::{ArmenianUpper, RomanUpper};
+
+    assert_eq!(
+        BigUint::from(1_984_u16).to_nominal(&RomanUpper),
+        "MCMLXXXIV"
+    );
+    assert_eq!(BigUint::from(9_999_u16).to_nominal(&ArmenianUpper), "ՔՋՂԹ");
+}
+
+#[test]
+fn chinese() {
+    use crate::{Error, SimplifiedChineseFormal, TraditionalChineseInformal};
+
+    assert_eq!(
+        ten_to_the(48).to_nominal(&TraditionalChineseInformal::default()),
+        "一極"
+    );
+    assert_eq!(
+        (ten_to_the(52) * 3_u8 + ten_to_the(48)).to_nominal(&TraditionalChineseInformal::default()),
+        "三恆河沙零一極"
+    );
+    assert_eq!(
+        ten_to_the(68).to_nominal(&SimplifiedChineseFormal::default()),
+        "一无量大数"
+    );
+    assert_eq!(
+        (ten_to_the(72) - 1_u8).to_nominal(&TraditionalChineseInformal::default()),
+        "九千九百九十九無量大數九千九百九十九不可思議九千九百九十九那由他\
+         九千九百九十九阿僧祇九千九百九十九恆河沙九千九百九十九極\
+         九千九百九十九載九千九百九十九正九千九百九十九澗九千九百九十九溝\
+         九千九百九十九穰九千九百九十九秭九千九百九十九垓九千九百九十九京\
+         九千九百九十九兆九千九百九十九億九千九百九十九萬九千九百九十九"
+    );
+    assert_eq!(
+        ten_to_the(72).try_to_nominal(&TraditionalChineseInformal::default()),
+        Err(Error::OutOfBounds(ten_to_the(72)))
+    );
+
+    assert_eq!(
+        ten_to_the(80).to_nominal(&TraditionalChineseInformal::default().mid_scale()),
+        "一載"
+    );
+    assert_eq!(
+        (ten_to_the(64) + ten_to_the(40))
+            .to_nominal(&TraditionalChineseInformal::default().mid_scale()),
+        "一澗零一秭"
+    );
+    assert_eq!(
+        ten_to_the(64).to_nominal(&TraditionalChineseInformal::default().long_scale()),
+        "一垓"
+    );
+    assert_eq!(
+        ten_to_the(4096).to_nominal(&TraditionalChineseInformal::default().long_scale()),
+        "一載"
+    );
+}
@@ -1,6 +1,11 @@
 use core::array;
+use core::ops::Index;
 
-use crate::{Error, Nominal, NominalString, NominalSystem, OutOfMemoryError};
+use crate::succession::step_parsed;
+use crate::{
+    CjkDecimal, Decimal, Error, NominalString, NominalSuccession, NominalSystem, OutOfMemoryError,
+    SuccessionError, UnsignedInteger, WithNominal,
+};
 
 const SIMPLIFIED_ORDINARY: [char; 14] = [
     '零', '一', '二', '三', '四', '五', '六', '七', '八', '九', '十', '百', '千', '负',
@@ -15,29 +20,63 @@ const TRADITIONAL_FINANCIAL: [char; 14] = [
     '零', '壹', '貳', '參', '肆', '伍', '陸', '柒', '捌', '玖', '拾', '佰', '仟', '負',
 ];
 
-const TRADITIONAL_LARGE: [char; 11] = [
-    '萬', '億', '兆', '京', '垓', '秭', '穰', '溝', '澗', '正', '載',
+const TRADITIONAL_LARGE: [&str; 17] = [
+    "萬",
+    "億",
+    "兆",
+    "京",
+    "垓",
+    "秭",
+    "穰",
+    "溝",
+    "澗",
+    "正",
+    "載",
+    "極",
+    "恆河沙",
+    "阿僧祇",
+    "那由他",
+    "不可思議",
+    "無量大數",
 ];
-const SIMPLIFIED_LARGE: [char; 11] = [
-    '万', '亿', '兆', '京', '垓', '秭', '穰', '沟', '涧', '正', '载',
+const SIMPLIFIED_LARGE: [&str; 17] = [
+    "万",
+    "亿",
+    "兆",
+    "京",
+    "垓",
+    "秭",
+    "穰",
+    "沟",
+    "涧",
+    "正",
+    "载",
+    "极",
+    "恒河沙",
+    "阿僧祇",
+    "那由他",
+    "不可思议",
+    "无量大数",
 ];
 
 fn format_chinese<T, const FORMAL: bool>(
-    characters: &[char; 14],
-    large_characters: &[char; 11],
+    characters: &ChineseCharacters,
+    large_characters: &[&str; 17],
     nominal: T,
     scale: ChineseScale,
 ) -> Result<NominalString, Error<T>>
 where
-    T: Nominal + TryFrom<u128>,
+    T: UnsignedInteger,
 {
     if nominal.is_zero() {
         return Ok(NominalString::from(characters[0]));
+    } else if characters.phone {
+        return format_chinese_digits(characters, &nominal);
     }
     if let Ok(ten_thousand) = T::try_from(10_000usize) {
         if nominal >= ten_thousand {
             return scale
-                .format::<T, FORMAL>(characters, large_characters, nominal)
+                .format::<T, FORMAL>(characters, large_characters, nominal.clone())
                 .map_err(|err| match err {
                     ChineseFormatError::OutOfMemory => Error::OutOfMemory(nominal),
                     ChineseFormatError::OutOfBounds => Error::OutOfBounds(nominal),
@@ -45,28 +84,175 @@ where
         }
     }
 
-    format_chinese_inner::<T, FORMAL>(characters, nominal, true, &mut false)
+    format_chinese_inner::<T, FORMAL>(characters, nominal.clone(), true, &mut false)
         .map_err(|_| Error::OutOfMemory(nominal))
 }
 
+/// Reads each digit of `nominal` individually, as is done for phone numbers:
+/// 110 is 幺幺零.
+fn format_chinese_digits<T>(
+    characters: &ChineseCharacters,
+    nominal: &T,
+) -> Result<NominalString, Error<T>>
+where
+    T: UnsignedInteger,
+{
+    let ten = T::from(10);
+    let mut formatted = NominalString::new_reverse();
+    let mut remaining = nominal.clone();
+    while !remaining.is_zero() {
+        let digit = match (remaining.clone() % ten.clone()).as_usize() {
+            1 => '幺',
+            digit => characters[digit],
+        };
+        formatted
+            .try_push_front(digit)
+            .with_nominal(nominal.clone())?;
+        remaining /= ten.clone();
+    }
+    Ok(formatted)
+}
+
+fn format_chinese_decimal<T, const FORMAL: bool>(
+    characters: &ChineseCharacters,
+    large_characters: &[&str; 17],
+    point: char,
+    value: T,
+    fraction_digits: u32,
+    scale: ChineseScale,
+) -> Result<NominalString, Error<T>>
+where
+    T: UnsignedInteger,
+{
+    // When `T` cannot hold 10^fraction_digits, every digit of `value` is a
+    // fractional digit.
+    let (integer, fraction) = match power_of_ten::<T>(fraction_digits) {
+        Some(divisor) => (value.clone() / divisor.clone(), value.clone() % divisor),
+        None => (T::from(0), value.clone()),
+    };
+    let mut formatted = format_chinese::<T, FORMAL>(characters, large_characters, integer, scale)
+        .map_err(|err| with_value(&err, value.clone()))?;
+    push_fraction(
+        &mut formatted,
+        characters,
+        point,
+        &fraction,
+        fraction_digits,
+    )
+    .with_nominal(value)?;
+    Ok(formatted)
+}
+
+/// Writes the `fraction_digits` least significant digits of `fraction` after
+/// `point`, one digit at a time: 3.14 is 三点一四.
+fn push_fraction<T>(
+    formatted: &mut NominalString,
+    characters: &ChineseCharacters,
+    point: char,
+    fraction: &T,
+    fraction_digits: u32,
+) -> Result<(), OutOfMemoryError>
+where
+    T: UnsignedInteger,
+{
+    if fraction_digits > 0 {
+        formatted.try_push(point)?;
+    }
+    for exponent in (0..fraction_digits).rev() {
+        let digit = power_of_ten::<T>(exponent).map_or(0, |place| {
+            (fraction.clone() / place % T::from(10)).as_usize()
+        });
+        formatted.try_push(characters[digit])?;
+    }
+    Ok(())
+}
+
+fn format_chinese_currency<T, const FORMAL: bool>(
+    characters: &ChineseCharacters,
+    large_characters: &[&str; 17],
+    fen: T,
+    scale: ChineseScale,
+) -> Result<NominalString, Error<T>>
+where
+    T: UnsignedInteger,
+{
+    let ten = T::from(10);
+    let yuan = fen.clone() / T::from(100);
+    let jiao = (fen.clone() / ten.clone() % ten.clone()).as_usize();
+    let cents = (fen.clone() % ten.clone()).as_usize();
+
+    let mut formatted = if yuan.is_zero() {
+        NominalString::new()
+    } else {
+        let mut formatted =
+            format_chinese::<T, FORMAL>(characters, large_characters, yuan.clone(), scale)
+                .map_err(|err| with_value(&err, fen.clone()))?;
+        formatted.try_push('元').with_nominal(fen.clone())?;
+        formatted
+    };
+
+    if jiao == 0 && cents == 0 {
+        if yuan.is_zero() {
+            formatted
+                .try_push(characters[0])
+                .and_then(|()| formatted.try_push('元'))
+                .with_nominal(fen.clone())?;
+        }
+    } else {
+        // A single 零 is written after 元 when the ones digit of the yuan or
+        // the 角 digit is zero, so that no digits can be inserted between.
+        if !yuan.is_zero() && (jiao == 0 || (yuan % ten).is_zero()) {
+            formatted
+                .try_push(characters[0])
+                .with_nominal(fen.clone())?;
+        }
+        if jiao > 0 {
+            formatted
+                .try_push(characters[jiao])
+                .and_then(|()| formatted.try_push('角'))
+                .with_nominal(fen.clone())?;
+        }
+        if cents > 0 {
+            formatted
+                .try_push(characters[cents])
+                .and_then(|()| formatted.try_push('分'))
+                .with_nominal(fen)?;
+            return Ok(formatted);
+        }
+    }
+
+    // Amounts without 分 end with 整 to prevent digits from being appended.
+    formatted.try_push('整').with_nominal(fen)?;
+    Ok(formatted)
+}
+
+/// Returns `err` with its nominal replaced by `value`.
+fn with_value<T>(err: &Error<T>, value: T) -> Error<T> {
+    match err {
+        Error::NoZeroSymbol => Error::NoZeroSymbol,
+        Error::OutOfBounds(_) => Error::OutOfBounds(value),
+        Error::OutOfMemory(_) => Error::OutOfMemory(value),
+    }
+}
+
 fn format_chinese_inner<T, const FORMAL: bool>(
-    characters: &[char; 14],
+    characters: &ChineseCharacters,
     nominal: T,
     no_prefix: bool,
     last_char_is_zero: &mut bool,
 ) -> Result<NominalString, OutOfMemoryError>
 where
-    T: Nominal,
+    T: UnsignedInteger,
 {
     let one = T::from(1);
     let ten = T::from(10);
 
     let mut remaining = nominal;
-    let ones = remaining % ten;
-    remaining /= ten;
-    let tens = remaining % ten;
-    remaining /= ten;
-    let hundreds = remaining % ten;
+    let ones = remaining.clone() % ten.clone();
+    remaining /= ten.clone();
+    let tens = remaining.clone() % ten.clone();
+    remaining /= ten.clone();
+    let hundreds = remaining.clone() % ten.clone();
     let thousands = remaining / ten;
 
     let mut formatted = NominalString::new();
@@ -77,7 +263,7 @@ where
             formatted.try_push(characters[0])?;
         }
     } else {
-        formatted.try_push(characters[thousands.as_usize()])?;
+        formatted.try_push(characters.before_unit(thousands.as_usize()))?;
         formatted.try_push(characters[12])?;
         *last_char_is_zero = false;
     }
@@ -88,7 +274,7 @@ where
             formatted.try_push(characters[0])?;
         }
     } else {
-        formatted.try_push(characters[hundreds.as_usize()])?;
+        formatted.try_push(characters.before_unit(hundreds.as_usize()))?;
         formatted.try_push(characters[11])?;
         *last_char_is_zero = false;
     }
@@ -98,6 +284,9 @@ where
             formatted.try_push(characters[0])?;
             *last_char_is_zero = true;
         }
+    } else if let Some(contracted) = characters.contracted_tens(tens.as_usize()) {
+        formatted.try_push(contracted)?;
+        *last_char_is_zero = false;
     } else {
         let omit_digit =
             !(FORMAL || (!formatted.is_empty() || !no_prefix) || *last_char_is_zero || tens != one);
@@ -120,6 +309,86 @@ where
 struct ChineseOptions {
     usage: ChineseUsage,
     scale: ChineseScale,
+    reading: ChineseReading,
+    liang: bool,
+    circle_zero: bool,
+    contracted_tens: bool,
+}
+
+impl ChineseOptions {
+    fn characters(
+        self,
+        ordinary: &[char; 14],
+        financial: &[char; 14],
+        liang: char,
+    ) -> ChineseCharacters {
+        let mut digits = match self.usage {
+            ChineseUsage::Financial => *financial,
+            ChineseUsage::Ordinary => *ordinary,
+        };
+        if self.circle_zero {
+            digits[0] = '〇';
+        }
+        ChineseCharacters {
+            digits,
+            liang: if self.liang { Some(liang) } else { None },
+            contracted_tens: self.contracted_tens,
+            phone: self.reading == ChineseReading::Digits,
+        }
+    }
+}
+
+/// The characters used to format a number, after applying the colloquial
+/// options of a Chinese system.
+struct ChineseCharacters {
+    digits: [char; 14],
+    liang: Option<char>,
+    contracted_tens: bool,
+    phone: bool,
+}
+
+impl ChineseCharacters {
+    /// Returns the character for `digit` when it is followed by 百, 千, or a
+    /// large character.
+    fn before_unit(&self, digit: usize) -> char {
+        match (digit, self.liang) {
+            (2, Some(liang)) => liang,
+            _ => self.digits[digit],
+        }
+    }
+
+    /// Returns 廿, 卅, or 卌 if `tens` should be written as a single character.
+    fn contracted_tens(&self, tens: usize) -> Option<char> {
+        match tens {
+            2..=4 if self.contracted_tens => Some(['廿', '卅', '卌'][tens - 2]),
+            _ => None,
+        }
+    }
+
+    /// Replaces the final 二 of `formatted`, which is followed by a large
+    /// character, with 两 if enabled.
+    fn replace_two_before_unit(
+        &self,
+        formatted: NominalString,
+    ) -> Result<NominalString, OutOfMemoryError> {
+        let Some(liang) = self.liang else {
+            return Ok(formatted);
+        };
+        let Some(prefix) = formatted.strip_suffix(self.digits[2]) else {
+            return Ok(formatted);
+        };
+        let mut replaced = NominalString::from(prefix);
+        replaced.try_push(liang)?;
+        Ok(replaced)
+    }
+}
+
+impl Index<usize> for ChineseCharacters {
+    type Output = char;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.digits[index]
+    }
 }
 
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
@@ -129,6 +398,13 @@ enum ChineseUsage {
     Ordinary,
 }
 
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+enum ChineseReading {
+    #[default]
+    Positional,
+    Digits,
+}
+
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
 enum ChineseScale {
     Short,
@@ -141,30 +417,30 @@ enum ChineseScale {
 impl ChineseScale {
     fn format<T, const FORMAL: bool>(
         self,
-        characters: &[char; 14],
-        large_characters: &[char; 11],
+        characters: &ChineseCharacters,
+        large_characters: &[&str; 17],
         nominal: T,
     ) -> Result<NominalString, ChineseFormatError>
     where
-        T: Nominal + TryFrom<u128>,
+        T: UnsignedInteger,
     {
         match self {
             ChineseScale::Short => {
                 Self::format_short_rank::<T, FORMAL>(10, characters, large_characters, nominal)
             }
-            ChineseScale::Myriad => Self::format_scaled::<T, FORMAL, 9>(
+            ChineseScale::Myriad => Self::format_scaled::<T, FORMAL, 17>(
                 MYRIAD_SCALE,
                 characters,
                 large_characters,
                 nominal,
             ),
-            ChineseScale::Mid => Self::format_scaled::<T, FORMAL, 5>(
+            ChineseScale::Mid => Self::format_scaled::<T, FORMAL, 11>(
                 MID_SCALE,
                 characters,
                 large_characters,
                 nominal,
             ),
-            ChineseScale::Long => Self::format_scaled::<T, FORMAL, 4>(
+            ChineseScale::Long => Self::format_scaled::<T, FORMAL, 11>(
                 LONG_SCALE,
                 characters,
                 large_characters,
@@ -175,14 +451,14 @@ impl ChineseScale {
 
     fn format_short_rank<T, const FORMAL: bool>(
         mut rank: usize,
-        characters: &[char; 14],
-        large_characters: &[char; 11],
+        characters: &ChineseCharacters,
+        large_characters: &[&str; 17],
         nominal: T,
     ) -> Result<NominalString, ChineseFormatError>
     where
-        T: Nominal + TryFrom<u128>,
+        T: UnsignedInteger,
     {
-        let Ok(mut scale) = T::try_from(SHORT_SCALE[rank]) else {
+        let Some(mut scale) = power_of_ten::<T>(SHORT_SCALE[rank]) else {
             return Self::format_short_rank::<T, FORMAL>(
                 rank - 1,
                 characters,
@@ -195,7 +471,7 @@ impl ChineseScale {
         let mut last_was_zero = false;
         let mut remaining = nominal;
         while !remaining.is_zero() {
-            let rank_value = remaining / scale;
+            let rank_value = remaining.clone() / scale.clone();
             if rank_value >= T::from(10) {
                 return Err(ChineseFormatError::OutOfBounds);
             }
@@ -208,8 +484,8 @@ impl ChineseScale {
                     formatted.try_push(characters[0])?;
                 }
             } else {
-                formatted.try_push(characters[rank_value.as_usize()])?;
-                formatted.try_push(large_characters[rank])?;
+                formatted.try_push(characters.before_unit(rank_value.as_usize()))?;
+                formatted.try_push_str(large_characters[rank])?;
                 last_was_zero = false;
             }
 
@@ -217,7 +493,7 @@ impl ChineseScale {
                 break;
             }
             rank -= 1;
-            let Ok(new_scale) = T::try_from(SHORT_SCALE[rank]) else {
+            let Some(new_scale) = power_of_ten::<T>(SHORT_SCALE[rank]) else {
                 unreachable!("rank + 1 was valid")
             };
             scale = new_scale;
@@ -236,17 +512,17 @@ impl ChineseScale {
     }
 
     fn format_scaled<T, const FORMAL: bool, const N: usize>(
-        scales: [u128; N],
-        characters: &[char; 14],
-        large_characters: &[char; 11],
+        scales: [u32; N],
+        characters: &ChineseCharacters,
+        large_characters: &[&str; 17],
         nominal: T,
     ) -> Result<NominalString, ChineseFormatError>
     where
-        T: Nominal + TryFrom<u128>,
+        T: UnsignedInteger,
     {
-        let scales: [Option<T>; N] = array::from_fn(|index| T::try_from(scales[index]).ok());
+        let scales: [Option<T>; N] = array::from_fn(|index| power_of_ten(scales[index]));
         Self::format_scaled_inner::<T, FORMAL, N>(
-            scales,
+            &scales,
             characters,
             large_characters,
             nominal,
@@ -256,18 +532,18 @@ impl ChineseScale {
     }
 
     fn format_scaled_inner<T, const FORMAL: bool, const N: usize>(
-        scales: [Option<T>; N],
-        characters: &[char; 14],
-        large_characters: &[char; 11],
+        scales: &[Option<T>; N],
+        characters: &ChineseCharacters,
+        large_characters: &[&str; 17],
         nominal: T,
         no_prefix: bool,
         last_char_is_zero: &mut bool,
     ) -> Result<NominalString, ChineseFormatError>
     where
-        T: Nominal + TryFrom<u128>,
+        T: UnsignedInteger,
     {
         Self::format_scaled_rank::<T, FORMAL, N>(
-            10,
+            N - 1,
             scales,
             characters,
             large_characters,
@@ -279,15 +555,15 @@ impl ChineseScale {
 
     fn format_next_scaled_rank<T, const FORMAL: bool, const N: usize>(
         rank: usize,
-        scales: [Option<T>; N],
-        characters: &[char; 14],
-        large_characters: &[char; 11],
+        scales: &[Option<T>; N],
+        characters: &ChineseCharacters,
+        large_characters: &[&str; 17],
         nominal: T,
         no_prefix: bool,
         last_char_is_zero: &mut bool,
     ) -> Result<NominalString, ChineseFormatError>
     where
-        T: Nominal + TryFrom<u128>,
+        T: UnsignedInteger,
     {
         if let Some(rank) = rank.checked_sub(1) {
             Self::format_scaled_rank::<T, FORMAL, N>(
@@ -307,17 +583,17 @@ impl ChineseScale {
 
     fn format_scaled_rank<T, const FORMAL: bool, const N: usize>(
         rank: usize,
-        scales: [Option<T>; N],
-        characters: &[char; 14],
-        large_characters: &[char; 11],
+        scales: &[Option<T>; N],
+        characters: &ChineseCharacters,
+        large_characters: &[&str; 17],
         nominal: T,
         no_prefix: bool,
         last_char_is_zero: &mut bool,
     ) -> Result<NominalString, ChineseFormatError>
     where
-        T: Nominal + TryFrom<u128>,
+        T: UnsignedInteger,
     {
-        let Some(scale) = scales.get(rank).copied().flatten() else {
+        let Some(scale) = scales.get(rank).cloned().flatten() else {
             return Self::format_next_scaled_rank::<T, FORMAL, N>(
                 rank,
                 scales,
@@ -328,16 +604,16 @@ impl ChineseScale {
                 last_char_is_zero,
             );
         };
-        let remaining = nominal % scale;
-        let rank_value = nominal / scale;
+        let remaining = nominal.clone() % scale.clone();
+        let rank_value = nominal / scale.clone();
 
-        if let Some(previous_scale) = scales.get(rank + 1).copied().flatten() {
-            let factor = previous_scale / scale;
+        if let Some(Some(previous_scale)) = scales.get(rank + 1) {
+            let factor = previous_scale.clone() / scale;
             if rank_value >= factor {
                 return Err(ChineseFormatError::OutOfBounds);
             }
         } else if rank > 0 {
-            let factor = scale / scales[rank - 1].expect("rank is valid");
+            let factor = scale / scales[rank - 1].clone().expect("rank is valid");
             if rank_value >= factor {
                 return Err(ChineseFormatError::OutOfBounds);
             }
@@ -345,6 +621,7 @@ impl ChineseScale {
         let mut formatted = if rank_value.is_zero() {
             NominalString::new()
         } else {
+            let is_two = rank_value == T::from(2);
             let mut rank_formatted = Self::format_next_scaled_rank::<T, FORMAL, N>(
                 rank,
                 scales,
@@ -354,8 +631,11 @@ impl ChineseScale {
                 no_prefix,
                 last_char_is_zero,
             )?;
+            if is_two {
+                rank_formatted = characters.replace_two_before_unit(rank_formatted)?;
+            }
             if !rank_formatted.is_empty() {
-                rank_formatted.try_push(large_characters[rank])?;
+                rank_formatted.try_push_str(large_characters[rank])?;
                 *last_char_is_zero = false;
             }
             rank_formatted
@@ -389,46 +669,37 @@ impl From<OutOfMemoryError> for ChineseFormatError {
     }
 }
 
-const SHORT_SCALE: [u128; 11] = [
-    10_u128.pow(4),
-    10_u128.pow(5),
-    10_u128.pow(6),
-    10_u128.pow(7),
-    10_u128.pow(8),
-    10_u128.pow(9),
-    10_u128.pow(10),
-    10_u128.pow(11),
-    10_u128.pow(12),
-    10_u128.pow(13),
-    10_u128.pow(14),
-];
+/// Returns `10^exponent`, or `None` if it cannot be represented by `T`.
+fn power_of_ten<T>(mut exponent: u32) -> Option<T>
+where
+    T: UnsignedInteger,
+{
+    let mut result = T::from(1);
+    let mut base = T::from(10);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result.checked_mul(&base)?;
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = base.checked_mul(&base)?;
+        }
+    }
+    Some(result)
+}
 
-const MYRIAD_SCALE: [u128; 9] = [
-    10_u128.pow(4),
-    10_u128.pow(8),
-    10_u128.pow(12),
-    10_u128.pow(16),
-    10_u128.pow(20),
-    10_u128.pow(24),
-    10_u128.pow(28),
-    10_u128.pow(32),
-    10_u128.pow(36),
-];
+// Each scale is listed as the power of ten that each large character in
+// `TRADITIONAL_LARGE` and `SIMPLIFIED_LARGE` represents. Scales beyond the
+// range of `u128` are only reachable with the `bigint` feature.
+const SHORT_SCALE: [u32; 11] = [4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
 
-const MID_SCALE: [u128; 5] = [
-    10_u128.pow(4),
-    10_u128.pow(8),
-    10_u128.pow(16),
-    10_u128.pow(24),
-    10_u128.pow(32),
+const MYRIAD_SCALE: [u32; 17] = [
+    4, 8, 12, 16, 20, 24, 28, 32, 36, 40, 44, 48, 52, 56, 60, 64, 68,
 ];
 
-const LONG_SCALE: [u128; 4] = [
-    10_u128.pow(4),
-    10_u128.pow(8),
-    10_u128.pow(16),
-    10_u128.pow(32),
-];
+const MID_SCALE: [u32; 11] = [4, 8, 16, 24, 32, 40, 48, 56, 64, 72, 80];
+
+const LONG_SCALE: [u32; 11] = [4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096];
 
 /// Simplified Chinese Informal numerical system.
 ///
@@ -443,14 +714,13 @@ pub struct SimplifiedChineseInformal(ChineseOptions);
 
 impl<T> NominalSystem<T> for SimplifiedChineseInformal
 where
-    T: Nominal + TryFrom<u128>,
+    T: UnsignedInteger,
 {
     fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
-        let characters = match self.0.usage {
-            ChineseUsage::Financial => &SIMPLIFIED_FINANCIAL,
-            ChineseUsage::Ordinary => &SIMPLIFIED_ORDINARY,
-        };
-        format_chinese::<_, false>(characters, &SIMPLIFIED_LARGE, nominal, self.0.scale)
+        let characters = self
+            .0
+            .characters(&SIMPLIFIED_ORDINARY, &SIMPLIFIED_FINANCIAL, '两');
+        format_chinese::<_, false>(&characters, &SIMPLIFIED_LARGE, nominal, self.0.scale)
     }
 }
 
@@ -467,14 +737,13 @@ pub struct SimplifiedChineseFormal(ChineseOptions);
 
 impl<T> NominalSystem<T> for SimplifiedChineseFormal
 where
-    T: Nominal + TryFrom<u128>,
+    T: UnsignedInteger,
 {
     fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
-        let characters = match self.0.usage {
-            ChineseUsage::Financial => &SIMPLIFIED_FINANCIAL,
-            ChineseUsage::Ordinary => &SIMPLIFIED_ORDINARY,
-        };
-        format_chinese::<_, true>(characters, &SIMPLIFIED_LARGE, nominal, self.0.scale)
+        let characters = self
+            .0
+            .characters(&SIMPLIFIED_ORDINARY, &SIMPLIFIED_FINANCIAL, '两');
+        format_chinese::<_, true>(&characters, &SIMPLIFIED_LARGE, nominal, self.0.scale)
     }
 }
 
@@ -491,14 +760,13 @@ pub struct TraditionalChineseFormal(ChineseOptions);
 
 impl<T> NominalSystem<T> for TraditionalChineseFormal
 where
-    T: Nominal + TryFrom<u128>,
+    T: UnsignedInteger,
 {
     fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
-        let characters = match self.0.usage {
-            ChineseUsage::Financial => &TRADITIONAL_FINANCIAL,
-            ChineseUsage::Ordinary => &TRADITIONAL_ORDINARY,
-        };
-        format_chinese::<_, true>(characters, &TRADITIONAL_LARGE, nominal, self.0.scale)
+        let characters = self
+            .0
+            .characters(&TRADITIONAL_ORDINARY, &TRADITIONAL_FINANCIAL, '兩');
+        format_chinese::<_, true>(&characters, &TRADITIONAL_LARGE, nominal, self.0.scale)
     }
 }
 
@@ -514,14 +782,13 @@ pub struct TraditionalChineseInformal(ChineseOptions);
 
 impl<T> NominalSystem<T> for TraditionalChineseInformal
 where
-    T: Nominal + TryFrom<u128>,
+    T: UnsignedInteger,
 {
     fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
-        let characters = match self.0.usage {
-            ChineseUsage::Financial => &TRADITIONAL_FINANCIAL,
-            ChineseUsage::Ordinary => &TRADITIONAL_ORDINARY,
-        };
-        format_chinese::<_, false>(characters, &TRADITIONAL_LARGE, nominal, self.0.scale)
+        let characters = self
+            .0
+            .characters(&TRADITIONAL_ORDINARY, &TRADITIONAL_FINANCIAL, '兩');
+        format_chinese::<_, false>(&characters, &TRADITIONAL_LARGE, nominal, self.0.scale)
     }
 }
 
@@ -563,6 +830,40 @@ macro_rules! impl_chinese_options {
                 self.0.scale = ChineseScale::Long;
                 self
             }
+
+            /// Returns a variation that writes 两 (兩) instead of 二 before 百,
+            /// 千, and the large characters, such as `两千` and `两万`, as is
+            /// common in spoken Chinese.
+            #[must_use]
+            pub const fn liang(mut self) -> Self {
+                self.0.liang = true;
+                self
+            }
+
+            /// Returns a variation that writes 〇 instead of 零, such as
+            /// `一千〇五`.
+            #[must_use]
+            pub const fn circle_zero(mut self) -> Self {
+                self.0.circle_zero = true;
+                self
+            }
+
+            /// Returns a variation that writes 20, 30, and 40 as the single
+            /// characters 廿, 卅, and 卌, such as `廿一` for 21.
+            #[must_use]
+            pub const fn contracted_tens(mut self) -> Self {
+                self.0.contracted_tens = true;
+                self
+            }
+
+            /// Returns a variation that reads each digit individually and
+            /// writes one as 幺, as is done for phone and room numbers. For
+            /// example, 110 is formatted as `幺幺零`.
+            #[must_use]
+            pub const fn phone_style(mut self) -> Self {
+                self.0.reading = ChineseReading::Digits;
+                self
+            }
         }
     };
 }
@@ -572,6 +873,663 @@ impl_chinese_options!(TraditionalChineseInformal);
 impl_chinese_options!(SimplifiedChineseFormal);
 impl_chinese_options!(SimplifiedChineseInformal);
 
+macro_rules! impl_chinese_decimal {
+    ($type:ident, $formal:literal, $ordinary:ident, $financial:ident, $large:ident, $liang:literal, $point:literal) => {
+        impl $type {
+            #[doc = concat!("Tries to format `value` as a fixed-point number with `fraction_digits` digits after the decimal point (", $point, ").")]
+            ///
+            /// The integer part is formatted using this system, and each
+            /// fractional digit is written individually. For example, 314
+            /// with 2 fractional digits is formatted as `三点一四`.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`Error::OutOfBounds`] if the integer part cannot be
+            /// formatted, or [`Error::OutOfMemory`] if the formatted number
+            /// cannot be stored.
+            pub fn try_format_decimal<T>(
+                &self,
+                value: T,
+                fraction_digits: u32,
+            ) -> Result<NominalString, Error<T>>
+            where
+                T: UnsignedInteger,
+            {
+                let characters = self.0.characters(&$ordinary, &$financial, $liang);
+                format_chinese_decimal::<T, $formal>(
+                    &characters,
+                    &$large,
+                    $point,
+                    value,
+                    fraction_digits,
+                    self.0.scale,
+                )
+            }
+
+            /// Tries to format an amount of currency using 元, 角, and 分,
+            /// where `fen` is the amount in hundredths of a 元.
+            ///
+            /// Zeros are collapsed following the rules for writing amounts on
+            /// Chinese cheques: at most one `零` is written between 元 and the
+            /// fractional units, and amounts without 分 end with `整`. For
+            /// example, 12,345 is formatted as `壹佰贰拾叁元肆角伍分` by the
+            /// simplified formal financial system, and 10,005 as
+            /// `壹佰元零伍分`.
+            ///
+            /// Cheques require the formal financial variation, which writes
+            /// `壹拾` instead of `拾`.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`Error::OutOfBounds`] if the number of 元 cannot be
+            /// formatted, or [`Error::OutOfMemory`] if the formatted amount
+            /// cannot be stored.
+            pub fn try_format_currency<T>(&self, fen: T) -> Result<NominalString, Error<T>>
+            where
+                T: UnsignedInteger,
+            {
+                let characters = self.0.characters(&$ordinary, &$financial, $liang);
+                format_chinese_currency::<T, $formal>(&characters, &$large, fen, self.0.scale)
+            }
+        }
+    };
+}
+
+impl_chinese_decimal!(
+    TraditionalChineseFormal,
+    true,
+    TRADITIONAL_ORDINARY,
+    TRADITIONAL_FINANCIAL,
+    TRADITIONAL_LARGE,
+    '兩',
+    '點'
+);
+impl_chinese_decimal!(
+    TraditionalChineseInformal,
+    false,
+    TRADITIONAL_ORDINARY,
+    TRADITIONAL_FINANCIAL,
+    TRADITIONAL_LARGE,
+    '兩',
+    '點'
+);
+impl_chinese_decimal!(
+    SimplifiedChineseFormal,
+    true,
+    SIMPLIFIED_ORDINARY,
+    SIMPLIFIED_FINANCIAL,
+    SIMPLIFIED_LARGE,
+    '两',
+    '点'
+);
+impl_chinese_decimal!(
+    SimplifiedChineseInformal,
+    false,
+    SIMPLIFIED_ORDINARY,
+    SIMPLIFIED_FINANCIAL,
+    SIMPLIFIED_LARGE,
+    '两',
+    '点'
+);
+
+const JAPANESE_LARGE: [&str; 17] = [
+    "万",
+    "億",
+    "兆",
+    "京",
+    "垓",
+    "𥝱",
+    "穣",
+    "溝",
+    "澗",
+    "正",
+    "載",
+    "極",
+    "恒河沙",
+    "阿僧祇",
+    "那由他",
+    "不可思議",
+    "無量大数",
+];
+const JAPANESE_FORMAL_LARGE: [&str; 17] = [
+    "萬",
+    "億",
+    "兆",
+    "京",
+    "垓",
+    "𥝱",
+    "穣",
+    "溝",
+    "澗",
+    "正",
+    "載",
+    "極",
+    "恒河沙",
+    "阿僧祇",
+    "那由他",
+    "不可思議",
+    "無量大数",
+];
+const HANGEUL_LARGE: [&str; 17] = [
+    "만",
+    "억",
+    "조",
+    "경",
+    "해",
+    "자",
+    "양",
+    "구",
+    "간",
+    "정",
+    "재",
+    "극",
+    "항하사",
+    "아승기",
+    "나유타",
+    "불가사의",
+    "무량대수",
+];
+
+const JAPANESE_FORMAL: MyriadSystem = MyriadSystem {
+    digits: ["零", "壱", "弐", "参", "四", "伍", "六", "七", "八", "九"],
+    units: ["阡", "百", "拾"],
+    large: &JAPANESE_FORMAL_LARGE,
+    omit_one: false,
+    separator: None,
+};
+const JAPANESE_INFORMAL: MyriadSystem = MyriadSystem {
+    digits: ["〇", "一", "二", "三", "四", "五", "六", "七", "八", "九"],
+    units: ["千", "百", "十"],
+    large: &JAPANESE_LARGE,
+    omit_one: true,
+    separator: None,
+};
+const HANGEUL_FORMAL: MyriadSystem = MyriadSystem {
+    digits: ["영", "일", "이", "삼", "사", "오", "육", "칠", "팔", "구"],
+    units: ["천", "백", "십"],
+    large: &HANGEUL_LARGE,
+    omit_one: false,
+    separator: None,
+};
+const HANJA_INFORMAL: MyriadSystem = MyriadSystem {
+    digits: ["零", "一", "二", "三", "四", "五", "六", "七", "八", "九"],
+    units: ["千", "百", "十"],
+    large: &TRADITIONAL_LARGE,
+    omit_one: true,
+    separator: None,
+};
+const HANJA_FORMAL: MyriadSystem = MyriadSystem {
+    digits: ["零", "壹", "貳", "參", "四", "五", "六", "七", "八", "九"],
+    units: ["仟", "百", "拾"],
+    large: &TRADITIONAL_LARGE,
+    omit_one: false,
+    separator: None,
+};
+
+/// A numeral system that writes each group of four digits using digits and the
+/// units 10, 100, and 1,000, followed by the group's myriad scale character.
+///
+/// Unlike the Chinese systems, no zero is written between groups or digits.
+struct MyriadSystem {
+    digits: [&'static str; 10],
+    // The units for 1,000, 100, and 10.
+    units: [&'static str; 3],
+    large: &'static [&'static str; 17],
+    // When true, the one before each unit is omitted: `十` instead of `一十`.
+    omit_one: bool,
+    separator: Option<char>,
+}
+
+impl MyriadSystem {
+    fn format<T>(&self, nominal: T) -> Result<NominalString, Error<T>>
+    where
+        T: UnsignedInteger,
+    {
+        if nominal.is_zero() {
+            return Ok(NominalString::from(self.digits[0]));
+        }
+
+        let Some(groups) = myriad_groups(&nominal) else {
+            return Err(Error::OutOfBounds(nominal));
+        };
+
+        let mut formatted = NominalString::new();
+        for (rank, &group) in groups.iter().enumerate().rev() {
+            if group == 0 {
+                continue;
+            }
+
+            if let Some(separator) = self.separator.filter(|_| !formatted.is_empty()) {
+                formatted
+                    .try_push(separator)
+                    .with_nominal(nominal.clone())?;
+            }
+            self.format_group(group, &mut formatted)
+                .with_nominal(nominal.clone())?;
+            if rank > 0 {
+                formatted
+                    .try_push_str(self.large[rank - 1])
+                    .with_nominal(nominal.clone())?;
+            }
+        }
+
+        Ok(formatted)
+    }
+
+    fn format_group(
+        &self,
+        group: usize,
+        formatted: &mut NominalString,
+    ) -> Result<(), OutOfMemoryError> {
+        for (unit, place) in self.units.into_iter().zip([1000, 100, 10]) {
+            let digit = group / place % 10;
+            if digit > 0 {
+                if digit > 1 || !self.omit_one {
+                    formatted.try_push_str(self.digits[digit])?;
+                }
+                formatted.try_push_str(unit)?;
+            }
+        }
+        if group % 10 > 0 {
+            formatted.try_push_str(self.digits[group % 10])?;
+        }
+        Ok(())
+    }
+
+    /// Returns the value of `nominal`, or `None` if `nominal` contains text
+    /// that isn't part of this system.
+    ///
+    /// Any arrangement of digits and units is accepted, which only guarantees
+    /// the correct value for nominals this system formats.
+    fn parse(&self, nominal: &str) -> Option<u128> {
+        if nominal.is_empty() {
+            return None;
+        }
+
+        let mut value = 0_u128;
+        let mut group = 0_u128;
+        let mut digit = None;
+        let mut remaining = nominal;
+        while let Some(next) = remaining.chars().next() {
+            let symbol = self
+                .digits
+                .into_iter()
+                .zip(0_u128..)
+                .find(|(symbol, _)| remaining.starts_with(symbol));
+            // Some Hangeul scale syllables are also digits, such as 구, which is
+            // both nine and 10^32. A digit can only follow a unit, so these are
+            // read as a digit unless a digit was just read.
+            if let (None, Some((symbol, symbol_value))) = (digit, symbol) {
+                digit = Some(symbol_value);
+                remaining = &remaining[symbol.len()..];
+            } else if let Some((exponent, large)) = MYRIAD_SCALE
+                .iter()
+                .zip(self.large)
+                .find(|(_, large)| remaining.starts_with(**large))
+            {
+                group += digit.take().unwrap_or(0);
+                if group == 0 {
+                    return None;
+                }
+                let scale = 10_u128.checked_pow(*exponent)?;
+                value = value.checked_add(group.checked_mul(scale)?)?;
+                group = 0;
+                remaining = &remaining[large.len()..];
+            } else if let Some((unit, place)) = self
+                .units
+                .into_iter()
+                .zip([1000, 100, 10])
+                .find(|(unit, _)| remaining.starts_with(unit))
+            {
+                group += digit.take().unwrap_or(1) * place;
+                remaining = &remaining[unit.len()..];
+            } else if self.separator == Some(next) {
+                remaining = &remaining[next.len_utf8()..];
+            } else {
+                return None;
+            }
+        }
+
+        value.checked_add(group + digit.unwrap_or(0))
+    }
+}
+
+/// Splits `nominal` into groups of four digits, starting with the least
+/// significant group, or returns `None` if `nominal` is too large for the
+/// myriad scale.
+fn myriad_groups<T>(nominal: &T) -> Option<[usize; MYRIAD_SCALE.len() + 1]>
+where
+    T: UnsignedInteger,
+{
+    let myriad = power_of_ten::<T>(MYRIAD_SCALE[0]);
+    let mut groups = [0_usize; MYRIAD_SCALE.len() + 1];
+    let mut remaining = nominal.clone();
+    for group in &mut groups {
+        if remaining.is_zero() {
+            break;
+        }
+        if let Some(myriad) = &myriad {
+            *group = (remaining.clone() % myriad.clone()).as_usize();
+            remaining /= myriad.clone();
+        } else {
+            // `T` cannot hold 10,000, so the entire number is one group.
+            *group = core::mem::replace(&mut remaining, T::from(0)).as_usize();
+        }
+    }
+    remaining.is_zero().then_some(groups)
+}
+
+macro_rules! impl_myriad_system {
+    ($type:ident, $system:ident) => {
+        impl<T> NominalSystem<T> for $type
+        where
+            T: UnsignedInteger,
+        {
+            fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+                $system.format(nominal)
+            }
+        }
+
+        impl NominalSuccession for $type {
+            fn next_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+                step_parsed(
+                    nominal,
+                    true,
+                    |nominal| $system.parse(nominal),
+                    |value| $system.format(value),
+                )
+            }
+
+            fn prev_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+                step_parsed(
+                    nominal,
+                    false,
+                    |nominal| $system.parse(nominal),
+                    |value| $system.format(value),
+                )
+            }
+        }
+    };
+}
+
+/// Formal Japanese Kanji numbering.
+///
+/// Numbers are grouped by powers of 10,000 using 萬, 億, 兆, and larger
+/// characters, such as `弐萬` for 20,000.
+#[doc = include_str!("./previews/JapaneseFormal.md")]
+pub struct JapaneseFormal;
+
+impl_myriad_system!(JapaneseFormal, JAPANESE_FORMAL);
+
+/// Informal Japanese Kanji numbering.
+///
+/// Numbers are grouped by powers of 10,000 using 万, 億, 兆, and larger
+/// characters, such as `二万` for 20,000.
+#[doc = include_str!("./previews/JapaneseInformal.md")]
+pub struct JapaneseInformal;
+
+impl_myriad_system!(JapaneseInformal, JAPANESE_INFORMAL);
+
+/// Korean Hangeul/Hangul numbering.
+///
+/// Numbers are grouped by powers of 10,000 using 만, 억, 조, and larger
+/// syllables, such as `이만` for 20,000.
+///
+/// ```rust
+/// use nominals::{HangeulFormal, Nominal};
+///
+/// let hangeul = HangeulFormal::default();
+/// assert_eq!(
+///     1_2345_6789_u32.to_nominal(&hangeul),
+///     "일억이천삼백사십오만육천칠백팔십구"
+/// );
+/// assert_eq!(
+///     1_2345_6789_u32.to_nominal(&hangeul.spaced().without_leading_one()),
+///     "일억 이천삼백사십오만 육천칠백팔십구"
+/// );
+/// ```
+///
+/// [Why Hangeul and not Hangul?](https://github.com/khonsulabs/nominals/issues/6)
+#[doc = include_str!("./previews/HangeulFormal.md")]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct HangeulFormal(KoreanOptions);
+
+impl HangeulFormal {
+    /// Returns a variation that separates each group of four digits with a
+    /// space, following Korean spacing rules, such as `일억 이천만`.
+    #[must_use]
+    pub const fn spaced(mut self) -> Self {
+        self.0.spaced = true;
+        self
+    }
+
+    /// Returns a variation that omits `일` before `십`, `백`, and `천`, such as
+    /// `천백십` instead of `일천일백일십`.
+    #[must_use]
+    pub const fn without_leading_one(mut self) -> Self {
+        self.0.omit_one = true;
+        self
+    }
+
+    const fn system(self) -> MyriadSystem {
+        MyriadSystem {
+            omit_one: self.0.omit_one,
+            separator: if self.0.spaced { Some(' ') } else { None },
+            ..HANGEUL_FORMAL
+        }
+    }
+}
+
+impl<T> NominalSystem<T> for HangeulFormal
+where
+    T: UnsignedInteger,
+{
+    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+        self.system().format(nominal)
+    }
+}
+
+impl NominalSuccession for HangeulFormal {
+    fn next_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        let system = self.system();
+        step_parsed(
+            nominal,
+            true,
+            |nominal| system.parse(nominal),
+            |value| system.format(value),
+        )
+    }
+
+    fn prev_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        let system = self.system();
+        step_parsed(
+            nominal,
+            false,
+            |nominal| system.parse(nominal),
+            |value| system.format(value),
+        )
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+struct KoreanOptions {
+    spaced: bool,
+    omit_one: bool,
+}
+
+/// Informal Korean Hanja numbering.
+///
+/// Numbers are grouped by powers of 10,000 using 萬, 億, 兆, and larger
+/// characters, such as `二萬` for 20,000.
+#[doc = include_str!("./previews/HanjaInformal.md")]
+pub struct HanjaInformal;
+
+impl_myriad_system!(HanjaInformal, HANJA_INFORMAL);
+
+/// Formal Korean Hanja numbering.
+///
+/// Numbers are grouped by powers of 10,000 using 萬, 億, 兆, and larger
+/// characters, such as `貳萬` for 20,000.
+#[doc = include_str!("./previews/HanjaFormal.md")]
+pub struct HanjaFormal;
+
+impl_myriad_system!(HanjaFormal, HANJA_FORMAL);
+
+/// Decimal digits grouped by powers of 10,000 using CJK large characters, such
+/// as `3万5000` and `1億2000万`.
+///
+/// Each group of four digits is formatted using [`Decimal`] or, with
+/// [`cjk_digits()`](Self::cjk_digits), [`CjkDecimal`], followed by the
+/// group's large character. Every group after the first is padded to four
+/// digits so that `1万0005` cannot be read as 15,000. Trailing groups that are
+/// zero are never written. By default, groups that are zero between nonzero
+/// groups are written.
+///
+/// ```rust
+/// use nominals::{MyriadDecimal, Nominal};
+///
+/// let japanese = MyriadDecimal::japanese();
+/// assert_eq!(1_2000_0000_u32.to_nominal(&japanese), "1億2000万");
+/// assert_eq!(1_0000_0005_u32.to_nominal(&japanese), "1億0000万0005");
+/// let japanese = japanese.omit_zero_groups();
+/// assert_eq!(1_0000_0005_u32.to_nominal(&japanese), "1億0005");
+/// assert_eq!(3_5000_u32.to_nominal(&japanese.thousands()), "3万5千");
+/// assert_eq!(
+///     3_5000_u32.to_nominal(&MyriadDecimal::traditional().cjk_digits()),
+///     "三萬五〇〇〇"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct MyriadDecimal {
+    large: &'static [&'static str; 17],
+    cjk_digits: bool,
+    omit_zero_groups: bool,
+    thousands: bool,
+}
+
+impl MyriadDecimal {
+    /// Returns a system that uses simplified Chinese large characters:
+    /// 万, 亿, 兆, and larger.
+    #[must_use]
+    pub const fn simplified() -> Self {
+        Self::new(&SIMPLIFIED_LARGE)
+    }
+
+    /// Returns a system that uses traditional Chinese large characters:
+    /// 萬, 億, 兆, and larger.
+    #[must_use]
+    pub const fn traditional() -> Self {
+        Self::new(&TRADITIONAL_LARGE)
+    }
+
+    /// Returns a system that uses Japanese large characters: 万, 億, 兆, and
+    /// larger.
+    #[must_use]
+    pub const fn japanese() -> Self {
+        Self::new(&JAPANESE_LARGE)
+    }
+
+    const fn new(large: &'static [&'static str; 17]) -> Self {
+        Self {
+            large,
+            cjk_digits: false,
+            omit_zero_groups: false,
+            thousands: false,
+        }
+    }
+
+    /// Returns a variation that formats each group using [`CjkDecimal`]
+    /// instead of [`Decimal`], such as `三万五〇〇〇`.
+    #[must_use]
+    pub const fn cjk_digits(mut self) -> Self {
+        self.cjk_digits = true;
+        self
+    }
+
+    /// Returns a variation that omits groups that are zero, such as `1億0005`
+    /// instead of `1億0000万0005`.
+    #[must_use]
+    pub const fn omit_zero_groups(mut self) -> Self {
+        self.omit_zero_groups = true;
+        self
+    }
+
+    /// Returns a variation that writes groups that are a multiple of 1,000
+    /// using 千, such as `3万5千` instead of `3万5000`.
+    #[must_use]
+    pub const fn thousands(mut self) -> Self {
+        self.thousands = true;
+        self
+    }
+
+    fn push_group(
+        &self,
+        group: usize,
+        padded: bool,
+        formatted: &mut NominalString,
+    ) -> Result<(), Error<usize>> {
+        let (digits, zero): (&dyn NominalSystem<usize>, char) = if self.cjk_digits {
+            (&CjkDecimal, '〇')
+        } else {
+            (&Decimal, '0')
+        };
+        if self.thousands && group >= 1000 && group % 1000 == 0 {
+            formatted
+                .try_push_str(&digits.try_format_nominal(group / 1000)?)
+                .with_nominal(group)?;
+            formatted.try_push('千').with_nominal(group)
+        } else {
+            if padded {
+                for limit in [1000, 100, 10] {
+                    if group < limit {
+                        formatted.try_push(zero).with_nominal(group)?;
+                    }
+                }
+            }
+            formatted
+                .try_push_str(&digits.try_format_nominal(group)?)
+                .with_nominal(group)
+        }
+    }
+}
+
+impl<T> NominalSystem<T> for MyriadDecimal
+where
+    T: UnsignedInteger,
+{
+    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+        let Some(groups) = myriad_groups(&nominal) else {
+            return Err(Error::OutOfBounds(nominal));
+        };
+
+        // Trailing zero groups are never written, but a zero is still written
+        // for the ones group of zero.
+        let lowest = groups.iter().position(|&group| group != 0).unwrap_or(0);
+
+        let mut formatted = NominalString::new();
+        for (rank, &group) in groups.iter().enumerate().skip(lowest).rev() {
+            let omit = if formatted.is_empty() {
+                rank > 0
+            } else {
+                self.omit_zero_groups
+            };
+            if group == 0 && omit {
+                continue;
+            }
+
+            self.push_group(group, !formatted.is_empty(), &mut formatted)
+                .map_err(|_| Error::OutOfMemory(nominal.clone()))?;
+            if rank > 0 {
+                formatted
+                    .try_push_str(self.large[rank - 1])
+                    .with_nominal(nominal.clone())?;
+            }
+        }
+
+        Ok(formatted)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::string::String;
@@ -579,6 +1537,7 @@ mod tests {
     use chinese_number::NumberToChineseError;
 
     use super::*;
+    use crate::Nominal;
 
     #[track_caller]
     fn test_formatting<N>(
@@ -902,4 +1861,274 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn japanese() {
+        for (n, formal, informal) in [
+            (0_u128, "零", "〇"),
+            (10, "壱拾", "十"),
+            (1_111, "壱阡壱百壱拾壱", "千百十一"),
+            (1_0000, "壱萬", "一万"),
+            (2_0000, "弐萬", "二万"),
+            (1000_0000, "壱阡萬", "千万"),
+            (10_0001, "壱拾萬壱", "十万一"),
+            (3_0000_0500, "参億伍百", "三億五百"),
+            (1_0000_0000_0000, "壱兆", "一兆"),
+            (
+                u128::MAX,
+                "参百四拾澗弐阡八百弐拾参溝六阡六百九拾弐穣九百参拾八𥝱四阡六百参拾四垓六阡参百参拾七京四阡六百七兆四阡参百壱拾七億六阡八百弐拾壱萬壱阡四百伍拾伍",
+                "三百四十澗二千八百二十三溝六千六百九十二穣九百三十八𥝱四千六百三十四垓六千三百三十七京四千六百七兆四千三百十七億六千八百二十一万千四百五十五",
+            ),
+        ] {
+            assert_eq!(n.to_nominal(&JapaneseFormal), formal);
+            assert_eq!(n.to_nominal(&JapaneseInformal), informal);
+        }
+        assert_eq!(9_999_u16.to_nominal(&JapaneseInformal), "九千九百九十九");
+        assert_eq!(u8::MAX.to_nominal(&JapaneseInformal), "二百五十五");
+    }
+
+    #[test]
+    fn korean() {
+        let hangeul = HangeulFormal::default();
+        for (n, formal, hanja_formal, hanja_informal) in [
+            (0_u64, "영", "零", "零"),
+            (1_111, "일천일백일십일", "壹仟壹百壹拾壹", "千百十一"),
+            (2_0000, "이만", "貳萬", "二萬"),
+            (1_0000_0001, "일억일", "壹億壹", "一億一"),
+            (
+                12_3456_0000,
+                "일십이억삼천사백오십육만",
+                "壹拾貳億參仟四百五拾六萬",
+                "十二億三千四百五十六萬",
+            ),
+            (5_0000_0000_0000, "오조", "五兆", "五兆"),
+        ] {
+            assert_eq!(n.to_nominal(&hangeul), formal);
+            assert_eq!(n.to_nominal(&HanjaFormal), hanja_formal);
+            assert_eq!(n.to_nominal(&HanjaInformal), hanja_informal);
+        }
+
+        let spoken = hangeul.spaced().without_leading_one();
+        assert_eq!(1_111_u32.to_nominal(&spoken), "천백십일");
+        assert_eq!(1_0000_u32.to_nominal(&spoken), "일만");
+        assert_eq!(
+            12_3456_7891_u64.to_nominal(&spoken),
+            "십이억 삼천사백오십육만 칠천팔백구십일"
+        );
+        assert_eq!(1_0000_0001_u32.to_nominal(&spoken), "일억 일");
+    }
+
+    #[test]
+    fn myriad_succession() {
+        for n in (0_u32..30_000).chain(99_990_000..100_010_000) {
+            let formatted = JapaneseInformal.format_nominal(n);
+            let next = JapaneseInformal.format_nominal(n + 1);
+            assert_eq!(
+                JapaneseInformal.next_nominal(&formatted).unwrap(),
+                next,
+                "{n}"
+            );
+            assert_eq!(
+                JapaneseInformal.prev_nominal(&next).unwrap(),
+                formatted,
+                "{n}"
+            );
+        }
+
+        let spoken = HangeulFormal::default().spaced().without_leading_one();
+        assert_eq!(
+            spoken
+                .next_nominal("천구백구십구만 구천구백구십구")
+                .unwrap(),
+            "이천만"
+        );
+        assert_eq!(
+            spoken.prev_nominal("일억").unwrap(),
+            "구천구백구십구만 구천구백구십구"
+        );
+        assert_eq!(HanjaFormal.next_nominal("壹萬").unwrap(), "壹萬壹");
+        assert_eq!(
+            HangeulFormal::default().next_nominal("천"),
+            Err(SuccessionError::InvalidNominal)
+        );
+        assert_eq!(
+            JapaneseFormal.prev_nominal("零"),
+            Err(SuccessionError::NoPredecessor)
+        );
+    }
+
+    #[test]
+    fn decimal() {
+        let simplified = SimplifiedChineseInformal::default();
+        assert_eq!(
+            simplified.try_format_decimal(314_u32, 2).unwrap(),
+            "三点一四"
+        );
+        assert_eq!(
+            simplified.try_format_decimal(1005_u32, 3).unwrap(),
+            "一点零零五"
+        );
+        assert_eq!(
+            simplified.try_format_decimal(5_u32, 3).unwrap(),
+            "零点零零五"
+        );
+        assert_eq!(simplified.try_format_decimal(105_u32, 1).unwrap(), "十点五");
+        assert_eq!(simplified.try_format_decimal(12_u32, 0).unwrap(), "十二");
+        assert_eq!(
+            simplified.try_format_decimal(1_0000_0005_u32, 4).unwrap(),
+            "一万点零零零五"
+        );
+        assert_eq!(
+            TraditionalChineseFormal::default()
+                .financial()
+                .try_format_decimal(2_50_u32, 2)
+                .unwrap(),
+            "貳點伍零"
+        );
+        // u8 cannot hold 10^3, so every digit is fractional.
+        assert_eq!(
+            simplified.try_format_decimal(255_u8, 4).unwrap(),
+            "零点零二五五"
+        );
+    }
+
+    #[test]
+    fn currency() {
+        let cheque = SimplifiedChineseFormal::default().financial();
+        for (fen, expected) in [
+            (0_u64, "零元整"),
+            (5, "伍分"),
+            (50, "伍角整"),
+            (55, "伍角伍分"),
+            (100, "壹元整"),
+            (105, "壹元零伍分"),
+            (150, "壹元伍角整"),
+            (1000, "壹拾元整"),
+            (1005, "壹拾元零伍分"),
+            (1050, "壹拾元零伍角整"),
+            (10005, "壹佰元零伍分"),
+            (12345, "壹佰贰拾叁元肆角伍分"),
+            (10_000_000, "壹拾万元整"),
+            (168_032, "壹仟陆佰捌拾元零叁角贰分"),
+            (10_005_000_000, "壹亿零伍万元整"),
+        ] {
+            assert_eq!(cheque.try_format_currency(fen).unwrap(), expected, "{fen}");
+        }
+        assert_eq!(
+            SimplifiedChineseInformal::default()
+                .try_format_currency(1234_u32)
+                .unwrap(),
+            "十二元三角四分"
+        );
+        assert_eq!(
+            TraditionalChineseFormal::default()
+                .financial()
+                .try_format_currency(2_00_u32)
+                .unwrap(),
+            "貳元整"
+        );
+    }
+
+    #[test]
+    fn colloquial() {
+        let liang = SimplifiedChineseInformal::default().liang();
+        for (n, expected) in [
+            (2_u128, "二"),
+            (12, "十二"),
+            (20, "二十"),
+            (200, "两百"),
+            (2_000, "两千"),
+            (2_222, "两千两百二十二"),
+            (2_0000, "两万"),
+            (12_0000, "十二万"),
+            (2_0002_0000, "两亿零两万"),
+            (2_2000_0000, "两亿两千万"),
+            (2_0000_0000_0000, "两兆"),
+        ] {
+            assert_eq!(n.to_nominal(&liang), expected, "{n}");
+        }
+        assert_eq!(
+            2_0000_u32.to_nominal(&TraditionalChineseInformal::default().liang()),
+            "兩萬"
+        );
+        assert_eq!(
+            200_000_u32.to_nominal(&SimplifiedChineseInformal::default().liang().short_scale()),
+            "两亿"
+        );
+
+        let circle = SimplifiedChineseInformal::default().circle_zero();
+        assert_eq!(0_u32.to_nominal(&circle), "〇");
+        assert_eq!(1_005_u32.to_nominal(&circle), "一千〇五");
+        assert_eq!(circle.try_format_decimal(305_u32, 2).unwrap(), "三点〇五");
+
+        let contracted = SimplifiedChineseInformal::default().contracted_tens();
+        for (n, expected) in [
+            (10_u32, "十"),
+            (20, "廿"),
+            (21, "廿一"),
+            (30, "卅"),
+            (45, "卌五"),
+            (50, "五十"),
+            (125, "一百廿五"),
+        ] {
+            assert_eq!(n.to_nominal(&contracted), expected, "{n}");
+        }
+
+        let phone = SimplifiedChineseInformal::default().phone_style();
+        assert_eq!(0_u32.to_nominal(&phone), "零");
+        assert_eq!(110_u32.to_nominal(&phone), "幺幺零");
+        assert_eq!(
+            13_800_138_000_u64.to_nominal(&phone.circle_zero()),
+            "幺三八〇〇幺三八〇〇〇"
+        );
+    }
+
+    #[test]
+    fn myriad_decimal() {
+        let simplified = MyriadDecimal::simplified();
+        for (n, expected) in [
+            (0_u128, "0"),
+            (9_999, "9999"),
+            (1_0000, "1万"),
+            (1_0005, "1万0005"),
+            (3_5000, "3万5000"),
+            (1_0000_0000, "1亿"),
+            (1_0000_0001, "1亿0000万0001"),
+            (1_2000_0000, "1亿2000万"),
+            (1_2000_0030, "1亿2000万0030"),
+            (
+                u128::MAX,
+                "340涧2823沟6692穰0938秭4634垓6337京4607兆4317亿6821万1455",
+            ),
+        ] {
+            assert_eq!(n.to_nominal(&simplified), expected, "{n}");
+        }
+
+        let omitted = simplified.omit_zero_groups();
+        for (n, expected) in [
+            (0_u64, "0"),
+            (1_0000, "1万"),
+            (1_0005, "1万0005"),
+            (3_5000, "3万5000"),
+            (1_0000_0001, "1亿0001"),
+            (1_2000_0000, "1亿2000万"),
+            (5_0000_0300_0000, "5兆0300万"),
+        ] {
+            assert_eq!(n.to_nominal(&omitted), expected, "{n}");
+        }
+
+        let thousands = MyriadDecimal::japanese().omit_zero_groups().thousands();
+        assert_eq!(3_5000_u32.to_nominal(&thousands), "3万5千");
+        assert_eq!(1_2000_0000_u32.to_nominal(&thousands), "1億2千万");
+        assert_eq!(1_2500_0000_u32.to_nominal(&thousands), "1億2500万");
+        assert_eq!(
+            2_0000_0000_0000_u64.to_nominal(&MyriadDecimal::traditional().cjk_digits()),
+            "二兆"
+        );
+        assert_eq!(
+            2_0000_0000_0005_u64.to_nominal(&MyriadDecimal::traditional().cjk_digits()),
+            "二兆〇〇〇〇億〇〇〇〇萬〇〇〇五"
+        );
+        assert_eq!(u8::MAX.to_nominal(&simplified), "255");
+    }
 }
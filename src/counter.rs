@@ -0,0 +1,294 @@
+use crate::{Error, NominalString, NominalSystem, UnsignedInteger};
+
+/// A list counter that formats its value using a [`NominalSystem`].
+///
+/// This type implements the counting behavior of HTML ordered lists (`<ol
+/// start reversed>`) and the CSS `counter-reset`, `counter-set`, and
+/// `counter-increment` properties.
+///
+/// ```rust
+/// use nominals::{Counter, RomanLower};
+///
+/// let mut counter = Counter::<_, u32>::new(RomanLower);
+/// assert_eq!(counter.marker(), "i");
+/// counter.increment().unwrap();
+/// assert_eq!(counter.marker(), "ii");
+///
+/// // `<ol reversed>` containing three items.
+/// let counter = Counter::new(RomanLower).reversed(3_u32);
+/// assert_eq!(counter.collect::<Vec<_>>(), ["iii", "ii", "i"]);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Counter<S, T> {
+    system: S,
+    start: Option<T>,
+    value: Option<T>,
+    step: T,
+    item_count: Option<T>,
+    remaining: Option<T>,
+    exhausted: bool,
+}
+
+impl<S, T> Counter<S, T>
+where
+    S: NominalSystem<T>,
+    T: UnsignedInteger,
+{
+    /// Returns a counter that starts at 1 and counts upwards by 1.
+    pub fn new(system: S) -> Self {
+        Self {
+            system,
+            start: None,
+            value: None,
+            step: T::from(1),
+            item_count: None,
+            remaining: None,
+            exhausted: false,
+        }
+    }
+
+    /// Returns this counter, starting at `start`.
+    ///
+    /// This is equivalent to the `start` attribute of an HTML ordered list.
+    #[must_use]
+    pub fn starting_at(mut self, start: T) -> Self {
+        self.start = Some(start);
+        self.value = None;
+        self
+    }
+
+    /// Returns this counter, changing by `step` each time it is incremented.
+    #[must_use]
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Returns this counter, counting downwards for a list containing
+    /// `item_count` items.
+    ///
+    /// Unless a starting value is set using [`Counter::starting_at`], the
+    /// starting value is computed such that the last item's value is the
+    /// counter's step: `item_count * step`. When this value cannot be
+    /// represented by `T`, [`Counter::start`] returns an error.
+    ///
+    /// When used as an [`Iterator`], the counter stops after returning the
+    /// marker for the last of the `item_count` items.
+    #[must_use]
+    pub fn reversed(mut self, item_count: T) -> Self {
+        self.remaining = Some(item_count.clone());
+        self.item_count = Some(item_count);
+        self.value = None;
+        self
+    }
+
+    /// Returns the system used to format this counter.
+    pub const fn system(&self) -> &S {
+        &self.system
+    }
+
+    /// Returns the starting value of this counter.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] with the item count of a
+    /// [reversed](Counter::reversed) counter without an explicit starting
+    /// value when `item_count * step` cannot be represented by `T`.
+    pub fn start(&self) -> Result<T, Error<T>> {
+        match (&self.start, &self.item_count) {
+            (Some(start), _) => Ok(start.clone()),
+            (None, Some(item_count)) => item_count
+                .checked_mul(&self.step)
+                .ok_or_else(|| Error::OutOfBounds(item_count.clone())),
+            (None, None) => Ok(T::from(1)),
+        }
+    }
+
+    /// Returns the current value of this counter.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error returned by [`Counter::start`] if this counter's
+    /// value has not been changed since it was created or reset.
+    pub fn value(&self) -> Result<T, Error<T>> {
+        match &self.value {
+            Some(value) => Ok(value.clone()),
+            None => self.start(),
+        }
+    }
+
+    /// Sets the current value of this counter to `value`.
+    ///
+    /// This is equivalent to the CSS `counter-set` property.
+    pub fn set(&mut self, value: T) {
+        self.value = Some(value);
+        self.exhausted = false;
+    }
+
+    /// Sets the current value of this counter to its starting value.
+    ///
+    /// This is equivalent to the CSS `counter-reset` property.
+    pub fn reset(&mut self) {
+        self.value = None;
+        self.exhausted = false;
+        self.remaining.clone_from(&self.item_count);
+    }
+
+    /// Changes this counter's value by its step, counting downwards if this
+    /// counter is reversed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] with the current value if the new value
+    /// cannot be represented by `T`. The counter's value is not changed when
+    /// an error is returned.
+    pub fn increment(&mut self) -> Result<(), Error<T>> {
+        let step = self.step.clone();
+        if self.item_count.is_some() {
+            self.decrement_by(step)
+        } else {
+            self.increment_by(step)
+        }
+    }
+
+    /// Adds `amount` to this counter's value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] with the current value if the new value
+    /// cannot be represented by `T`. The counter's value is not changed when
+    /// an error is returned.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn increment_by(&mut self, amount: T) -> Result<(), Error<T>> {
+        let value = self.value()?;
+        self.value = Some(
+            value
+                .checked_add(&amount)
+                .ok_or(Error::OutOfBounds(value))?,
+        );
+        Ok(())
+    }
+
+    /// Subtracts `amount` from this counter's value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] with the current value if the new value
+    /// would be negative. The counter's value is not changed when an error is
+    /// returned.
+    pub fn decrement_by(&mut self, amount: T) -> Result<(), Error<T>> {
+        let value = self.value()?;
+        if amount > value {
+            return Err(Error::OutOfBounds(value));
+        }
+        self.value = Some(value - amount);
+        Ok(())
+    }
+
+    /// Returns the current value formatted using this counter's system.
+    ///
+    /// If the system is unable to format the value, the value is formatted
+    /// using [`Decimal`](crate::Decimal).
+    pub fn marker(&self) -> NominalString {
+        match self.value() {
+            Ok(value) => self.system.format_nominal(value),
+            Err(err) => err.into_decimal(),
+        }
+    }
+
+    /// Tries to format the current value using this counter's system.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error returned by [`Counter::value`] or by the system, such
+    /// as [`Error::OutOfBounds`] when the value is outside of the range the
+    /// system can represent.
+    pub fn try_marker(&self) -> Result<NominalString, Error<T>> {
+        self.system.try_format_nominal(self.value()?)
+    }
+}
+
+/// Returns the marker of the current value and increments the counter.
+///
+/// Iteration stops after the counter's value can no longer be incremented. A
+/// [reversed](Counter::reversed) counter also stops after returning the marker
+/// for its last item. When the counter's value cannot be computed, no markers
+/// are returned.
+impl<S, T> Iterator for Counter<S, T>
+where
+    S: NominalSystem<T>,
+    T: UnsignedInteger,
+{
+    type Item = NominalString;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+        let Ok(value) = self.value() else {
+            self.exhausted = true;
+            return None;
+        };
+        if let Some(remaining) = &mut self.remaining {
+            if remaining.is_zero() {
+                return None;
+            }
+            *remaining -= T::from(1);
+        }
+
+        let marker = self.system.format_nominal(value);
+        self.exhausted = self.increment().is_err();
+        Some(marker)
+    }
+}
+
+#[test]
+fn counting() {
+    use alloc::vec::Vec;
+
+    use crate::{CircledNumber, Decimal, DigitCollection, LetterLower};
+
+    let mut counter = Counter::new(Decimal).starting_at(5_u32).step(5);
+    assert_eq!(counter.marker(), "5");
+    counter.increment().unwrap();
+    assert_eq!(counter.marker(), "10");
+    counter.increment_by(3).unwrap();
+    assert_eq!(counter.marker(), "13");
+    counter.decrement_by(13).unwrap();
+    assert_eq!(counter.marker(), "0");
+    assert_eq!(counter.decrement_by(1), Err(Error::OutOfBounds(0)));
+    assert_eq!(counter.value(), Ok(0));
+    counter.set(u32::MAX);
+    assert_eq!(counter.increment(), Err(Error::OutOfBounds(u32::MAX)));
+    counter.reset();
+    assert_eq!(counter.marker(), "5");
+
+    let mut counter = Counter::new(LetterLower.one_based()).reversed(4_u8);
+    assert_eq!(counter.by_ref().collect::<Vec<_>>(), ["d", "c", "b", "a"]);
+    counter.reset();
+    assert_eq!(counter.collect::<Vec<_>>(), ["d", "c", "b", "a"]);
+
+    let counter = Counter::new(Decimal).step(2).reversed(3_u8);
+    assert_eq!(counter.collect::<Vec<_>>(), ["6", "4", "2"]);
+    let counter = Counter::new(Decimal).reversed(3_u8).step(2);
+    assert_eq!(counter.collect::<Vec<_>>(), ["6", "4", "2"]);
+
+    let counter = Counter::new(Decimal).reversed(3_u8).starting_at(10);
+    assert_eq!(counter.collect::<Vec<_>>(), ["10", "9", "8"]);
+    let counter = Counter::new(Decimal).starting_at(10).reversed(3_u8);
+    assert_eq!(counter.collect::<Vec<_>>(), ["10", "9", "8"]);
+
+    assert_eq!(Counter::new(Decimal).reversed(0_u8).next(), None);
+
+    let mut counter = Counter::new(Decimal).step(200).reversed(2_u8);
+    assert_eq!(counter.start(), Err(Error::OutOfBounds(2)));
+    assert_eq!(counter.try_marker(), Err(Error::OutOfBounds(2)));
+    assert_eq!(counter.increment(), Err(Error::OutOfBounds(2)));
+    assert_eq!(counter.next(), None);
+
+    let mut counter = Counter::new(CircledNumber).starting_at(50_u8);
+    assert_eq!(counter.try_marker().unwrap(), "㊿");
+    counter.increment().unwrap();
+    assert_eq!(counter.try_marker(), Err(Error::OutOfBounds(51)));
+    assert_eq!(counter.marker(), "51");
+}
@@ -1,4 +1,8 @@
-use crate::{Error, Nominal, NominalString, NominalSystem, UnsignedInteger, WithNominal};
+use crate::succession::step_parsed;
+use crate::{
+    Error, NominalString, NominalSuccession, NominalSystem, SuccessionError, UnsignedInteger,
+    WithNominal,
+};
 
 /// An ordered set of characters that can be treated as digits.
 ///
@@ -556,7 +560,7 @@ impl<const N: usize> DigitCollection for DigitSet<N, true> {
 impl<T, D> NominalSystem<T> for D
 where
     D: DigitCollection,
-    T: Nominal + UnsignedInteger,
+    T: UnsignedInteger,
 {
     fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
         let Ok(mut count) = T::try_from(self.len()) else {
@@ -580,7 +584,7 @@ where
         let one = T::from(1_u8);
         let mut formatted = NominalString::new_reverse();
 
-        let mut remaining = nominal;
+        let mut remaining = nominal.clone();
         let mut first_loop = true;
         while !remaining.is_zero() || first_loop {
             if !self.has_zero_digit() && (!self.zero_based() || !first_loop) {
@@ -588,13 +592,13 @@ where
                     return Err(Error::NoZeroSymbol);
                 }
 
-                remaining -= one;
+                remaining -= one.clone();
             }
             first_loop = false;
 
             formatted
-                .try_push_front(self.digit((remaining % count).as_usize()))
-                .with_nominal(nominal)?;
+                .try_push_front(self.digit((remaining.clone() % count.clone()).as_usize()))
+                .with_nominal(nominal.clone())?;
             remaining /= count;
             count = match T::try_from(self.len()) {
                 Ok(count) => count,
@@ -604,6 +608,163 @@ where
 
         Ok(formatted)
     }
+
+    fn try_format_successor(&self, previous: &str, nominal: T) -> Result<NominalString, Error<T>> {
+        // Fixed collections can run out of digits and need to use their
+        // fallback, so they are always formatted from scratch.
+        if self.fixed() || T::try_from(self.len()).is_err() {
+            return self.try_format_nominal(nominal);
+        }
+
+        match step_digits(self, previous, true) {
+            Ok(formatted) => Ok(formatted),
+            Err(SuccessionError::OutOfMemory) => Err(Error::OutOfMemory(nominal)),
+            Err(_) => self.try_format_nominal(nominal),
+        }
+    }
+}
+
+impl<D> NominalSuccession for D
+where
+    D: DigitCollection,
+{
+    fn next_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        if self.fixed() {
+            step_parsed(
+                nominal,
+                true,
+                |nominal| parse_digits(self, nominal),
+                |value| self.try_format_nominal(value),
+            )
+        } else {
+            step_digits(self, nominal, true)
+        }
+    }
+
+    fn prev_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        if self.fixed() {
+            step_parsed(
+                nominal,
+                false,
+                |nominal| parse_digits(self, nominal),
+                |value| self.try_format_nominal(value),
+            )
+        } else {
+            step_digits(self, nominal, false)
+        }
+    }
+}
+
+/// Adds or subtracts one from the digits of `nominal`, propagating carries and
+/// borrows through the preceding digits.
+fn step_digits<D>(
+    digits: &D,
+    nominal: &str,
+    forward: bool,
+) -> Result<NominalString, SuccessionError>
+where
+    D: DigitCollection + ?Sized,
+{
+    let len = digits.len();
+    let position = |ch: char| (0..len).find(|&index| digits.digit(index) == ch);
+    if nominal.is_empty()
+        || nominal.chars().any(|ch| position(ch).is_none())
+        || (digits.has_zero_digit()
+            && nominal.len() > digits.digit(0).len_utf8()
+            && nominal.starts_with(digits.digit(0)))
+    {
+        return Err(SuccessionError::InvalidNominal);
+    }
+
+    let mut formatted = NominalString::new_reverse();
+    let mut carry = true;
+    let mut chars = nominal.chars().rev().peekable();
+    while let Some(ch) = chars.next() {
+        if !carry {
+            formatted.try_push_front(ch)?;
+            continue;
+        }
+
+        let index = position(ch).expect("validated");
+        let most_significant = chars.peek().is_none();
+        let next = if forward {
+            carry = index + 1 == len;
+            if carry {
+                0
+            } else {
+                index + 1
+            }
+        } else {
+            carry = index == 0;
+            if carry {
+                // A single first digit is the first nominal. Otherwise, only
+                // bijective systems can borrow from the most significant
+                // digit, which removes it.
+                if most_significant {
+                    if formatted.is_empty() {
+                        return Err(SuccessionError::NoPredecessor);
+                    }
+                    break;
+                }
+                len - 1
+            } else {
+                index - 1
+            }
+        };
+
+        // Positional systems never display a leading zero.
+        if !forward
+            && most_significant
+            && next == 0
+            && digits.has_zero_digit()
+            && !formatted.is_empty()
+        {
+            break;
+        }
+        formatted.try_push_front(digits.digit(next))?;
+    }
+
+    if carry && forward {
+        // A carry out of the most significant digit adds a new digit. In
+        // bijective systems, the first digit represents one rather than
+        // zero.
+        formatted.try_push_front(digits.digit(usize::from(digits.has_zero_digit())))?;
+    }
+
+    Ok(formatted)
+}
+
+/// Returns the value of `nominal` in `digits`, or `None` if it is not a
+/// sequence of digits from the collection or its fallback.
+pub(crate) fn parse_digits<D>(digits: &D, nominal: &str) -> Option<u128>
+where
+    D: DigitCollection + ?Sized,
+{
+    let len = digits.len();
+    let position = |ch: char| (0..len).find(|&index| digits.digit(index) == ch);
+    if digits.fixed() {
+        let mut chars = nominal.chars();
+        return match (chars.next().and_then(position), chars.next()) {
+            (Some(index), None) => u128::try_from(index + usize::from(!digits.zero_based())).ok(),
+            _ => parse_digits(&D::Fallback::default(), nominal),
+        };
+    }
+
+    let bijective = !digits.has_zero_digit();
+    let base = u128::try_from(len).ok()?;
+    let mut value = 0_u128;
+    for ch in nominal.chars() {
+        let digit = u128::try_from(position(ch)? + usize::from(bijective)).ok()?;
+        value = value.checked_mul(base)?.checked_add(digit)?;
+    }
+
+    if nominal.is_empty() {
+        None
+    } else if bijective && digits.zero_based() {
+        Some(value - 1)
+    } else {
+        Some(value)
+    }
 }
 
 /// An empty [`DigitCollection`] that is intended to be used to indicate no
@@ -628,7 +789,6 @@ impl DigitCollection for NoFallback {
 }
 
 /// An ordered collection of digits that can be used as a [`NominalSystem`].
-
 pub trait DigitCollection {
     /// The digit collection that should be used after this collection's range
     /// is exhausted.
@@ -701,3 +861,66 @@ fn basic_digits() {
 
     assert_eq!(core::mem::size_of::<NominalString>(), 64);
 }
+
+#[test]
+fn succession() {
+    fn assert_round_trips<S>(system: &S, start: u32)
+    where
+        S: NominalSystem<u32> + NominalSuccession,
+    {
+        for n in start..start + 2_000 {
+            let formatted = system.format_nominal(n);
+            let next = system.format_nominal(n + 1);
+            assert_eq!(system.next_nominal(&formatted).unwrap(), next, "{n}");
+            assert_eq!(system.prev_nominal(&next).unwrap(), formatted, "{n}");
+        }
+    }
+
+    assert_eq!(Decimal.next_nominal("9").unwrap(), "10");
+    assert_eq!(Decimal.next_nominal("1299").unwrap(), "1300");
+    assert_eq!(Decimal.prev_nominal("1300").unwrap(), "1299");
+    assert_eq!(Decimal.prev_nominal("100").unwrap(), "99");
+    assert_eq!(Decimal.prev_nominal("1").unwrap(), "0");
+    assert_eq!(
+        Decimal.prev_nominal("0"),
+        Err(SuccessionError::NoPredecessor)
+    );
+    for invalid in ["", "007", "1a"] {
+        assert_eq!(
+            Decimal.next_nominal(invalid),
+            Err(SuccessionError::InvalidNominal)
+        );
+    }
+
+    assert_eq!(LetterLower.next_nominal("z").unwrap(), "aa");
+    assert_eq!(LetterLower.next_nominal("azz").unwrap(), "baa");
+    assert_eq!(LetterLower.prev_nominal("aa").unwrap(), "z");
+    assert_eq!(LetterLower.prev_nominal("ba").unwrap(), "az");
+    assert_eq!(
+        LetterLower.prev_nominal("a"),
+        Err(SuccessionError::NoPredecessor)
+    );
+    assert_eq!(LetterUpper.one_based().next_nominal("ZZ").unwrap(), "AAA");
+    assert_eq!(
+        LetterUpper.one_based().prev_nominal("A"),
+        Err(SuccessionError::NoPredecessor)
+    );
+
+    assert_eq!(CjkHeavenlyStem.next_nominal("甲").unwrap(), "乙");
+    assert_eq!(CjkHeavenlyStem.next_nominal("癸").unwrap(), "一一");
+    assert_eq!(CjkHeavenlyStem.prev_nominal("一一").unwrap(), "癸");
+    assert_eq!(
+        CjkHeavenlyStem.prev_nominal("甲"),
+        Err(SuccessionError::NoPredecessor)
+    );
+    assert_eq!(
+        CjkHeavenlyStem.next_nominal("〇"),
+        Err(SuccessionError::InvalidNominal)
+    );
+
+    assert_round_trips(&Decimal, 0);
+    assert_round_trips(&HexUpper, 0);
+    assert_round_trips(&LetterLower, 0);
+    assert_round_trips(&LetterUpper.one_based(), 1);
+    assert_round_trips(&CjkEarthlyBranch, 1);
+}
@@ -0,0 +1,416 @@
+use crate::{Error, NominalString, NominalSystem, OutOfMemoryError, UnsignedInteger, WithNominal};
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+// The short scale name of each power of 1,000, starting with 1,000^1.
+const SCALE: [&str; 12] = [
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+];
+
+/// Spelled out English cardinal numbers, such as `one hundred twenty-three`.
+///
+/// Numbers are named using the short scale, which supports every value below
+/// one thousand undecillion (10<sup>39</sup>). This includes every `u128`.
+///
+/// ```rust
+/// use nominals::{EnglishCardinal, Nominal};
+///
+/// assert_eq!(
+///     123_u32.to_nominal(&EnglishCardinal::default()),
+///     "one hundred twenty-three"
+/// );
+/// assert_eq!(
+///     123_u32.to_nominal(&EnglishCardinal::default().with_and()),
+///     "one hundred and twenty-three"
+/// );
+/// assert_eq!(
+///     123_u32.to_nominal(&EnglishCardinal::default().title_case()),
+///     "One Hundred Twenty-Three"
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct EnglishCardinal(EnglishOptions);
+
+impl<T> NominalSystem<T> for EnglishCardinal
+where
+    T: UnsignedInteger,
+{
+    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+        format_english(nominal, self.0, false)
+    }
+}
+
+/// Spelled out English ordinal numbers, such as `twenty-first`.
+///
+/// Numbers are named using the short scale, which supports every value below
+/// one thousand undecillion (10<sup>39</sup>). This includes every `u128`.
+///
+/// ```rust
+/// use nominals::{EnglishOrdinal, Nominal};
+///
+/// assert_eq!(21_u32.to_nominal(&EnglishOrdinal::default()), "twenty-first");
+/// assert_eq!(
+///     1_000_u32.to_nominal(&EnglishOrdinal::default().title_case()),
+///     "One Thousandth"
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct EnglishOrdinal(EnglishOptions);
+
+impl<T> NominalSystem<T> for EnglishOrdinal
+where
+    T: UnsignedInteger,
+{
+    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+        format_english(nominal, self.0, true)
+    }
+}
+
+macro_rules! impl_english_options {
+    ($type:ident) => {
+        impl $type {
+            /// Returns a variation that inserts `and` before the tens and ones
+            /// of a number, as is common in British English. For example, 123
+            /// is written `one hundred and twenty-three`, and 1,005 is written
+            /// `one thousand and five`.
+            #[must_use]
+            pub const fn with_and(mut self) -> Self {
+                self.0.and = true;
+                self
+            }
+
+            /// Returns a variation that separates tens and ones with a space
+            /// instead of a hyphen, such as `twenty three`.
+            #[must_use]
+            pub const fn without_hyphens(mut self) -> Self {
+                self.0.hyphenate = false;
+                self
+            }
+
+            /// Returns a variation that capitalizes each word except `and`,
+            /// such as `One Hundred and Twenty-Three`.
+            #[must_use]
+            pub const fn title_case(mut self) -> Self {
+                self.0.title_case = true;
+                self
+            }
+        }
+    };
+}
+
+impl_english_options!(EnglishCardinal);
+impl_english_options!(EnglishOrdinal);
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct EnglishOptions {
+    and: bool,
+    hyphenate: bool,
+    title_case: bool,
+}
+
+impl Default for EnglishOptions {
+    fn default() -> Self {
+        Self {
+            and: false,
+            hyphenate: true,
+            title_case: false,
+        }
+    }
+}
+
+fn format_english<T>(
+    nominal: T,
+    options: EnglishOptions,
+    ordinal: bool,
+) -> Result<NominalString, Error<T>>
+where
+    T: UnsignedInteger,
+{
+    let thousand = T::try_from(1_000_usize).ok();
+
+    // Split the number into groups of three digits, starting with the least
+    // significant group.
+    let mut groups = [0_usize; SCALE.len() + 1];
+    let mut remaining = nominal.clone();
+    for group in &mut groups {
+        if remaining.is_zero() {
+            break;
+        }
+        if let Some(thousand) = &thousand {
+            *group = (remaining.clone() % thousand.clone()).as_usize();
+            remaining /= thousand.clone();
+        } else {
+            // `T` cannot hold 1,000, so the entire number is one group.
+            *group = core::mem::replace(&mut remaining, T::from(0)).as_usize();
+        }
+    }
+    if !remaining.is_zero() {
+        return Err(Error::OutOfBounds(nominal));
+    }
+
+    let mut words = Words::new(options);
+    if nominal.is_zero() {
+        words.push(ONES[0]).with_nominal(nominal.clone())?;
+    }
+    for (index, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+
+        let hundreds = group / 100;
+        let rest = group % 100;
+        if hundreds > 0 {
+            words.push(ONES[hundreds]).with_nominal(nominal.clone())?;
+            words.push("hundred").with_nominal(nominal.clone())?;
+        }
+
+        if rest > 0 {
+            // British English places `and` before the final tens and ones,
+            // even when there are no hundreds: `one thousand and five`.
+            let follows_larger_group = index == 0 && groups[1..].iter().any(|group| *group > 0);
+            if options.and && (hundreds > 0 || follows_larger_group) {
+                words.push("and").with_nominal(nominal.clone())?;
+            }
+
+            if rest < 20 {
+                words.push(ONES[rest]).with_nominal(nominal.clone())?;
+            } else {
+                words.push(TENS[rest / 10]).with_nominal(nominal.clone())?;
+                if rest % 10 > 0 {
+                    words.hyphenate_next = options.hyphenate;
+                    words.push(ONES[rest % 10]).with_nominal(nominal.clone())?;
+                }
+            }
+        }
+
+        if index > 0 {
+            words.push(SCALE[index - 1]).with_nominal(nominal.clone())?;
+        }
+    }
+
+    words.finish(ordinal).with_nominal(nominal)
+}
+
+/// Joins words into a nominal, delaying each word until the next word is
+/// pushed so that the last word can be made ordinal.
+struct Words {
+    formatted: NominalString,
+    pending: Option<(&'static str, bool)>,
+    hyphenate_next: bool,
+    title_case: bool,
+}
+
+impl Words {
+    fn new(options: EnglishOptions) -> Self {
+        Self {
+            formatted: NominalString::new(),
+            pending: None,
+            hyphenate_next: false,
+            title_case: options.title_case,
+        }
+    }
+
+    fn push(&mut self, word: &'static str) -> Result<(), OutOfMemoryError> {
+        let hyphenated = core::mem::take(&mut self.hyphenate_next);
+        if let Some((pending, pending_hyphenated)) = self.pending.replace((word, hyphenated)) {
+            self.write(pending, pending_hyphenated, "")?;
+        }
+        Ok(())
+    }
+
+    fn finish(mut self, ordinal: bool) -> Result<NominalString, OutOfMemoryError> {
+        if let Some((word, hyphenated)) = self.pending.take() {
+            if ordinal {
+                let (stem, suffix) = ordinal_word(word);
+                self.write(stem, hyphenated, suffix)?;
+            } else {
+                self.write(word, hyphenated, "")?;
+            }
+        }
+        Ok(self.formatted)
+    }
+
+    fn write(
+        &mut self,
+        word: &str,
+        hyphenated: bool,
+        suffix: &str,
+    ) -> Result<(), OutOfMemoryError> {
+        if !self.formatted.is_empty() {
+            self.formatted
+                .try_push(if hyphenated { '-' } else { ' ' })?;
+        }
+
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            if self.title_case && word != "and" {
+                self.formatted.try_push(first.to_ascii_uppercase())?;
+            } else {
+                self.formatted.try_push(first)?;
+            }
+        }
+        self.formatted.try_push_str(chars.as_str())?;
+        self.formatted.try_push_str(suffix)
+    }
+}
+
+/// Returns the stem and suffix of the ordinal form of `word`.
+fn ordinal_word(word: &'static str) -> (&'static str, &'static str) {
+    match word {
+        "one" => ("first", ""),
+        "two" => ("second", ""),
+        "three" => ("third", ""),
+        "five" => ("fifth", ""),
+        "eight" => ("eighth", ""),
+        "nine" => ("ninth", ""),
+        "twelve" => ("twelfth", ""),
+        _ => match word.strip_suffix('y') {
+            Some(stem) => (stem, "ieth"),
+            None => (word, "th"),
+        },
+    }
+}
+
+#[test]
+fn cardinal() {
+    use crate::Nominal;
+
+    let cardinal = EnglishCardinal::default();
+    for (n, expected) in [
+        (0_u128, "zero"),
+        (7, "seven"),
+        (13, "thirteen"),
+        (20, "twenty"),
+        (42, "forty-two"),
+        (100, "one hundred"),
+        (101, "one hundred one"),
+        (999, "nine hundred ninety-nine"),
+        (1_000, "one thousand"),
+        (1_005, "one thousand five"),
+        (
+            1_234_567,
+            "one million two hundred thirty-four thousand five hundred sixty-seven",
+        ),
+        (1_000_000_000_000, "one trillion"),
+        (10_u128.pow(36), "one undecillion"),
+    ] {
+        assert_eq!(n.to_nominal(&cardinal), expected);
+    }
+    assert_eq!(
+        u128::MAX.to_nominal(&cardinal),
+        "three hundred forty undecillion two hundred eighty-two decillion three hundred \
+         sixty-six nonillion nine hundred twenty octillion nine hundred thirty-eight \
+         septillion four hundred sixty-three sextillion four hundred sixty-three \
+         quintillion three hundred seventy-four quadrillion six hundred seven trillion \
+         four hundred thirty-one billion seven hundred sixty-eight million two hundred \
+         eleven thousand four hundred fifty-five"
+    );
+
+    assert_eq!(5_u8.to_nominal(&cardinal), "five");
+    assert_eq!(u8::MAX.to_nominal(&cardinal), "two hundred fifty-five");
+    assert_eq!(
+        u16::MAX.to_nominal(&cardinal),
+        "sixty-five thousand five hundred thirty-five"
+    );
+
+    let british = cardinal.with_and();
+    assert_eq!(123_u32.to_nominal(&british), "one hundred and twenty-three");
+    assert_eq!(1_005_u32.to_nominal(&british), "one thousand and five");
+    assert_eq!(
+        1_205_u32.to_nominal(&british),
+        "one thousand two hundred and five"
+    );
+    assert_eq!(
+        2_000_100_u32.to_nominal(&british),
+        "two million one hundred"
+    );
+    assert_eq!(1_100_u32.to_nominal(&british), "one thousand one hundred");
+    assert_eq!(99_u32.to_nominal(&british), "ninety-nine");
+
+    assert_eq!(45_u32.to_nominal(&cardinal.without_hyphens()), "forty five");
+    assert_eq!(
+        1_121_u32.to_nominal(&british.title_case()),
+        "One Thousand One Hundred and Twenty-One"
+    );
+}
+
+#[test]
+fn ordinal() {
+    use crate::Nominal;
+
+    let ordinal = EnglishOrdinal::default();
+    for (n, expected) in [
+        (0_u32, "zeroth"),
+        (1, "first"),
+        (2, "second"),
+        (3, "third"),
+        (4, "fourth"),
+        (5, "fifth"),
+        (8, "eighth"),
+        (9, "ninth"),
+        (11, "eleventh"),
+        (12, "twelfth"),
+        (20, "twentieth"),
+        (21, "twenty-first"),
+        (90, "ninetieth"),
+        (100, "one hundredth"),
+        (112, "one hundred twelfth"),
+        (1_000_000, "one millionth"),
+    ] {
+        assert_eq!(n.to_nominal(&ordinal), expected);
+    }
+
+    assert_eq!(
+        101_u32.to_nominal(&ordinal.with_and()),
+        "one hundred and first"
+    );
+    assert_eq!(
+        32_u32.to_nominal(&ordinal.without_hyphens().title_case()),
+        "Thirty Second"
+    );
+    assert_eq!(5_u8.to_nominal(&ordinal), "fifth");
+    assert_eq!(u8::MAX.to_nominal(&ordinal), "two hundred fifty-fifth");
+    assert_eq!(
+        u16::MAX.to_nominal(&ordinal),
+        "sixty-five thousand five hundred thirty-fifth"
+    );
+    assert!(u128::MAX
+        .to_nominal(&ordinal)
+        .ends_with("four hundred fifty-fifth"));
+}
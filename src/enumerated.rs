@@ -1,4 +1,9 @@
-use crate::{Error, Nominal, NominalString, NominalSystem};
+use crate::digital::parse_digits;
+use crate::succession::step_parsed;
+use crate::{
+    CjkDecimal, Error, NominalString, NominalSuccession, NominalSystem, SuccessionError,
+    UnsignedInteger, WithNominal,
+};
 
 /// A set of enumerated symbols that form a [`NominalSystem`].
 pub struct EnumeratedSet<const N: usize, const HAS_ZERO: bool> {
@@ -25,12 +30,13 @@ impl<const N: usize> EnumeratedSet<N, false> {
 
 impl<const N: usize, const HAS_ZERO: bool, T> NominalSystem<T> for EnumeratedSet<N, HAS_ZERO>
 where
-    T: Nominal,
+    T: UnsignedInteger,
 {
     fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
         let n: usize = nominal
+            .clone()
             .try_into()
-            .map_err(|_| Error::OutOfBounds(nominal))?;
+            .map_err(|_| Error::OutOfBounds(nominal.clone()))?;
 
         if !HAS_ZERO && nominal.is_zero() {
             Err(Error::NoZeroSymbol)
@@ -54,7 +60,7 @@ macro_rules! impl_enum_set {
 
         impl<T> NominalSystem<T> for $name
         where
-            T: Nominal,
+            T: UnsignedInteger,
         {
             fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
                 EnumeratedSet::$kind($symbols)
@@ -66,7 +72,7 @@ macro_rules! impl_enum_set {
 
 impl_enum_set! {
     /// Circled numbers, ranging from 0 to 50.
-    CircledNumber, new, [ 
+    CircledNumber, new, [
         "⓪", "①", "②", "③", "④", "⑤", "⑥", "⑦", "⑧", "⑨", "⑩",
         "⑪", "⑫", "⑬", "⑭", "⑮", "⑯", "⑰", "⑱", "⑲", "⑳",
         "㉑", "㉒", "㉓", "㉔", "㉕", "㉖", "㉗", "㉘", "㉙", "㉚",
@@ -79,3 +85,234 @@ impl_enum_set! {
     /// Doubly-circled numbers, ranging from 1 to 10.
     DoubleCircledNumber, zeroless, ["⓵", "⓶", "⓷", "⓸", "⓹", "⓺", "⓻", "⓼", "⓽", "⓾"]
 }
+
+/// Footnote symbols: `*`, `†`, `‡`, `§`, `¶`, and `‖`.
+///
+/// After each symbol has been used once, the symbols are repeated: `**`, `††`,
+/// and so on. Similar to the CSS `symbolic` counter system, nominals that would
+/// require more than 60 symbols are out of bounds.
+#[doc = include_str!("./previews/FootnoteSymbol.md")]
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct FootnoteSymbol;
+
+impl<T> NominalSystem<T> for FootnoteSymbol
+where
+    T: UnsignedInteger,
+{
+    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+        const SYMBOLS: [char; 6] = ['*', '†', '‡', '§', '¶', '‖'];
+        const MAX_REPETITIONS: usize = 60;
+
+        if nominal.is_zero() {
+            return Err(Error::NoZeroSymbol);
+        }
+        let offset = nominal.clone() - T::from(1);
+        let count = T::from(6);
+        let symbol = SYMBOLS[(offset.clone() % count.clone()).as_usize()];
+        let repetitions = match (offset / count).try_into() {
+            Ok(repetitions) if repetitions < MAX_REPETITIONS => repetitions + 1,
+            _ => return Err(Error::OutOfBounds(nominal)),
+        };
+
+        let mut formatted = NominalString::new();
+        for _ in 0..repetitions {
+            formatted.try_push(symbol).with_nominal(nominal.clone())?;
+        }
+        Ok(formatted)
+    }
+}
+
+/// The Chinese sexagenary cycle: 甲子, 乙丑, 丙寅, … 癸亥.
+///
+/// Each nominal combines one of the ten heavenly stems with one of the twelve
+/// earthly branches, advancing both at once. Because only stems and branches
+/// of the same parity are combined, the cycle repeats after 60 nominals.
+///
+/// By default, nominals after 60 are formatted using [`CjkDecimal`], matching
+/// [`CjkHeavenlyStem`](crate::CjkHeavenlyStem) and
+/// [`CjkEarthlyBranch`](crate::CjkEarthlyBranch). [`Sexagenary::cyclic`]
+/// wraps around to 甲子 instead, which allows formatting years: the year 4 CE
+/// was a 甲子 year, so year `y` is nominal `y - 3`.
+///
+/// ```rust
+/// use nominals::{Nominal, Sexagenary};
+///
+/// assert_eq!(1_u32.to_nominal(&Sexagenary::default()), "甲子");
+/// assert_eq!(60_u32.to_nominal(&Sexagenary::default()), "癸亥");
+/// assert_eq!(61_u32.to_nominal(&Sexagenary::default()), "六一");
+///
+/// let years = Sexagenary::default().cyclic().zodiac();
+/// assert_eq!((2024_u32 - 3).to_nominal(&years), "甲辰龙");
+/// ```
+#[doc = include_str!("./previews/Sexagenary.md")]
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Sexagenary {
+    cyclic: bool,
+    zodiac: Option<&'static [char; 12]>,
+}
+
+impl Sexagenary {
+    const STEMS: [char; 10] = ['甲', '乙', '丙', '丁', '戊', '己', '庚', '辛', '壬', '癸'];
+    const BRANCHES: [char; 12] = [
+        '子', '丑', '寅', '卯', '辰', '巳', '午', '未', '申', '酉', '戌', '亥',
+    ];
+    const ZODIAC: [char; 12] = [
+        '鼠', '牛', '虎', '兔', '龙', '蛇', '马', '羊', '猴', '鸡', '狗', '猪',
+    ];
+    const TRADITIONAL_ZODIAC: [char; 12] = [
+        '鼠', '牛', '虎', '兔', '龍', '蛇', '馬', '羊', '猴', '雞', '狗', '豬',
+    ];
+
+    /// Returns a variation that wraps around to 甲子 after 癸亥 instead of
+    /// formatting nominals after 60 using [`CjkDecimal`].
+    #[must_use]
+    pub const fn cyclic(mut self) -> Self {
+        self.cyclic = true;
+        self
+    }
+
+    /// Returns a variation that writes the zodiac animal of the earthly
+    /// branch after each nominal using simplified characters, such as `甲子鼠`
+    /// and `甲辰龙`.
+    #[must_use]
+    pub const fn zodiac(mut self) -> Self {
+        self.zodiac = Some(&Self::ZODIAC);
+        self
+    }
+
+    /// Returns a variation that writes the zodiac animal of the earthly
+    /// branch after each nominal using traditional characters, such as
+    /// `甲辰龍`.
+    #[must_use]
+    pub const fn traditional_zodiac(mut self) -> Self {
+        self.zodiac = Some(&Self::TRADITIONAL_ZODIAC);
+        self
+    }
+
+    fn parse(nominal: &str) -> Option<u128> {
+        let mut chars = nominal.chars();
+        let stem = chars
+            .next()
+            .and_then(|ch| Self::STEMS.iter().position(|&stem| stem == ch));
+        let branch = chars
+            .next()
+            .and_then(|ch| Self::BRANCHES.iter().position(|&branch| branch == ch));
+        let (Some(stem), Some(branch)) = (stem, branch) else {
+            return parse_digits(&CjkDecimal, nominal);
+        };
+        // Any zodiac animal is validated by the round trip in step_parsed.
+        (0..60_u128)
+            .find(|index| index % 10 == stem as u128 && index % 12 == branch as u128)
+            .map(|index| index + 1)
+    }
+}
+
+impl<T> NominalSystem<T> for Sexagenary
+where
+    T: UnsignedInteger,
+{
+    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+        if nominal.is_zero() {
+            return Err(Error::NoZeroSymbol);
+        }
+        let offset = nominal.clone() - T::from(1);
+        let cycle = T::from(60);
+        if !self.cyclic && offset >= cycle {
+            return CjkDecimal.try_format_nominal(nominal);
+        }
+        let index = (offset % cycle).as_usize();
+
+        let mut formatted = NominalString::new();
+        formatted
+            .try_push(Self::STEMS[index % 10])
+            .with_nominal(nominal.clone())?;
+        formatted
+            .try_push(Self::BRANCHES[index % 12])
+            .with_nominal(nominal.clone())?;
+        if let Some(zodiac) = self.zodiac {
+            formatted
+                .try_push(zodiac[index % 12])
+                .with_nominal(nominal)?;
+        }
+        Ok(formatted)
+    }
+}
+
+impl NominalSuccession for Sexagenary {
+    fn next_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        step_parsed(nominal, true, Self::parse, |value| {
+            self.try_format_nominal(value)
+        })
+    }
+
+    fn prev_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        step_parsed(nominal, false, Self::parse, |value| {
+            self.try_format_nominal(value)
+        })
+    }
+}
+
+#[test]
+fn footnote_symbols() {
+    use crate::Nominal;
+
+    assert_eq!(1_u32.to_nominal(&FootnoteSymbol), "*");
+    assert_eq!(6_u32.to_nominal(&FootnoteSymbol), "‖");
+    assert_eq!(7_u32.to_nominal(&FootnoteSymbol), "**");
+    assert_eq!(14_u32.to_nominal(&FootnoteSymbol), "†††");
+    assert_eq!(360_u32.to_nominal(&FootnoteSymbol).chars().count(), 60);
+    assert_eq!(
+        361_u32.try_to_nominal(&FootnoteSymbol),
+        Err(Error::OutOfBounds(361))
+    );
+    assert_eq!(
+        0_u32.try_to_nominal(&FootnoteSymbol),
+        Err(Error::NoZeroSymbol)
+    );
+}
+
+#[test]
+fn sexagenary() {
+    use crate::Nominal;
+
+    let cycle = Sexagenary::default();
+    assert_eq!(1_u32.to_nominal(&cycle), "甲子");
+    assert_eq!(2_u32.to_nominal(&cycle), "乙丑");
+    assert_eq!(11_u32.to_nominal(&cycle), "甲戌");
+    assert_eq!(13_u32.to_nominal(&cycle), "丙子");
+    assert_eq!(60_u32.to_nominal(&cycle), "癸亥");
+    assert_eq!(61_u32.to_nominal(&cycle), "六一");
+    assert_eq!(0_u32.try_to_nominal(&cycle), Err(Error::NoZeroSymbol));
+
+    let cyclic = cycle.cyclic();
+    assert_eq!(61_u32.to_nominal(&cyclic), "甲子");
+    assert_eq!(120_u32.to_nominal(&cyclic), "癸亥");
+    assert_eq!((1984_u32 - 3).to_nominal(&cyclic), "甲子");
+    assert_eq!((2024_u32 - 3).to_nominal(&cyclic.zodiac()), "甲辰龙");
+    assert_eq!(
+        (2024_u32 - 3).to_nominal(&cyclic.traditional_zodiac()),
+        "甲辰龍"
+    );
+
+    assert_eq!(cycle.next_nominal("甲子").unwrap(), "乙丑");
+    assert_eq!(cycle.next_nominal("癸亥").unwrap(), "六一");
+    assert_eq!(cycle.prev_nominal("六一").unwrap(), "癸亥");
+    assert_eq!(cyclic.next_nominal("癸亥").unwrap(), "甲子");
+    assert_eq!(
+        cycle.prev_nominal("甲子"),
+        Err(SuccessionError::NoPredecessor)
+    );
+    assert_eq!(
+        cycle.next_nominal("甲丑"),
+        Err(SuccessionError::InvalidNominal)
+    );
+    assert_eq!(
+        cycle.next_nominal("六〇"),
+        Err(SuccessionError::InvalidNominal)
+    );
+    assert_eq!(cycle.zodiac().next_nominal("甲子鼠").unwrap(), "乙丑牛");
+    assert_eq!(
+        cycle.zodiac().next_nominal("甲子牛"),
+        Err(SuccessionError::InvalidNominal)
+    );
+}
@@ -1,4 +1,8 @@
-use crate::{Error, Nominal, NominalString, NominalSystem, OutOfMemoryError};
+use crate::succession::step_parsed;
+use crate::{
+    Error, NominalString, NominalSuccession, NominalSystem, OutOfMemoryError, SuccessionError,
+    UnsignedInteger,
+};
 
 /// Ethiopic numerical system.
 #[doc = include_str!("./previews/Ethiopic.md")]
@@ -13,7 +17,7 @@ const TENS: [char; 10] = [
     '\u{1379}', '\u{137A}',
 ];
 
-fn format_ethiopic<T: Nominal>(nominal: T) -> Result<NominalString, OutOfMemoryError> {
+fn format_ethiopic<T: UnsignedInteger>(nominal: T) -> Result<NominalString, OutOfMemoryError> {
     let ten = T::from(10);
 
     let mut formatted = NominalString::new_reverse();
@@ -24,10 +28,10 @@ fn format_ethiopic<T: Nominal>(nominal: T) -> Result<NominalString, OutOfMemoryE
             break;
         }
 
-        let first = remaining % ten;
-        remaining /= ten;
-        let second = remaining % ten;
-        remaining /= ten;
+        let first = remaining.clone() % ten.clone();
+        remaining /= ten.clone();
+        let second = remaining.clone() % ten.clone();
+        remaining /= ten.clone();
 
         let first_is_zero = first.is_zero();
         let second_is_zero = second.is_zero();
@@ -65,21 +69,107 @@ fn format_ethiopic<T: Nominal>(nominal: T) -> Result<NominalString, OutOfMemoryE
 
 impl<T> NominalSystem<T> for Ethiopic
 where
-    T: Nominal,
+    T: UnsignedInteger,
 {
     fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
         if nominal.is_zero() {
             return Err(Error::NoZeroSymbol);
         }
-        format_ethiopic(nominal).map_err(|_| Error::OutOfMemory(nominal))
+        format_ethiopic(nominal.clone()).map_err(|_| Error::OutOfMemory(nominal))
     }
 }
 
+impl NominalSuccession for Ethiopic {
+    fn next_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        step_parsed(nominal, true, parse_ethiopic, |value| {
+            self.try_format_nominal(value)
+        })
+    }
+
+    fn prev_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        step_parsed(nominal, false, parse_ethiopic, |value| {
+            self.try_format_nominal(value)
+        })
+    }
+}
+
+fn parse_ethiopic(nominal: &str) -> Option<u128> {
+    // Nominals are parsed from the least significant group of two digits.
+    // Each ፻ begins the next odd-indexed group, and each ፼ begins the next
+    // even-indexed group. Groups without digits were either removed because
+    // they were zero, or because they were one and followed by a separator.
+    let mut value = 0_u128;
+    let mut group_index = 0_u32;
+    let mut group = None;
+    let mut chars = nominal.chars().rev().peekable();
+    while let Some(ch) = chars.next() {
+        let separator_odd = match ch {
+            '\u{137B}' => true,
+            '\u{137C}' => false,
+            _ => {
+                let digit = if let Some(digit) = ONES.iter().position(|one| *one == ch) {
+                    digit
+                } else {
+                    TENS.iter().position(|ten| *ten == ch)? * 10
+                };
+                group = Some(group.unwrap_or(0) + u128::try_from(digit).ok()?);
+                if chars.peek().is_some() {
+                    continue;
+                }
+                // The most significant group is added below.
+                break;
+            }
+        };
+
+        let group_value = group.take().unwrap_or(u128::from(group_index % 2 == 1));
+        value = value.checked_add(group_value.checked_mul(100_u128.checked_pow(group_index)?)?)?;
+        group_index += 1;
+        if (group_index % 2 == 1) != separator_odd {
+            group_index += 1;
+        }
+        if chars.peek().is_none() {
+            group = Some(1);
+        }
+    }
+
+    if let Some(group) = group {
+        value = value.checked_add(group.checked_mul(100_u128.checked_pow(group_index)?)?)?;
+    }
+    Some(value)
+}
+
 #[test]
 fn ethiopic() {
+    use crate::Nominal;
+
     assert_eq!(1_u32.to_nominal(&Ethiopic), "፩");
     assert_eq!(100_u32.to_nominal(&Ethiopic), "፻");
     assert_eq!(101_u32.to_nominal(&Ethiopic), "፻፩");
     assert_eq!(78_010_092_u32.to_nominal(&Ethiopic), "፸፰፻፩፼፺፪");
     assert_eq!(780_100_000_092_u64.to_nominal(&Ethiopic), "፸፰፻፩፼፼፺፪");
+
+    assert_eq!(Ethiopic.next_nominal("፺፱").unwrap(), "፻");
+    assert_eq!(Ethiopic.prev_nominal("፼").unwrap(), "፺፱፻፺፱");
+    assert_eq!(Ethiopic.next_nominal("፸፰፻፩፼፼፺፪").unwrap(), "፸፰፻፩፼፼፺፫");
+    assert_eq!(
+        Ethiopic.prev_nominal("፩"),
+        Err(SuccessionError::NoPredecessor)
+    );
+    assert_eq!(
+        Ethiopic.next_nominal("፩፻"),
+        Err(SuccessionError::InvalidNominal)
+    );
+    for n in 1_u64..30_000 {
+        let formatted = n.to_nominal(&Ethiopic);
+        let next = (n + 1).to_nominal(&Ethiopic);
+        assert_eq!(Ethiopic.next_nominal(&formatted).unwrap(), next, "{n}");
+        assert_eq!(Ethiopic.prev_nominal(&next).unwrap(), formatted, "{n}");
+    }
+    for n in [1_u64, 99, 100, 9_999, 10_000, 10_099] {
+        let n = n * 1_000_000;
+        let formatted = n.to_nominal(&Ethiopic);
+        let next = (n + 1).to_nominal(&Ethiopic);
+        assert_eq!(Ethiopic.next_nominal(&formatted).unwrap(), next, "{n}");
+        assert_eq!(Ethiopic.prev_nominal(&next).unwrap(), formatted, "{n}");
+    }
 }
@@ -1,4 +1,33 @@
-use crate::{Error, Nominal, NominalString, NominalSystem, UnsignedInteger, WithNominal};
+use crate::succession::step_parsed;
+use crate::{
+    Error, NominalString, NominalSuccession, NominalSystem, SuccessionError, UnsignedInteger,
+    WithNominal,
+};
+
+const SYMBOLS: [(char, u32); 22] = [
+    ('ת', 400),
+    ('ש', 300),
+    ('ר', 200),
+    ('ק', 100),
+    ('צ', 90),
+    ('פ', 80),
+    ('ע', 70),
+    ('ס', 60),
+    ('נ', 50),
+    ('מ', 40),
+    ('ל', 30),
+    ('כ', 20),
+    ('י', 10),
+    ('ט', 9),
+    ('ח', 8),
+    ('ז', 7),
+    ('ו', 6),
+    ('ה', 5),
+    ('ד', 4),
+    ('ג', 3),
+    ('ב', 2),
+    ('א', 1),
+];
 
 /// Hebrew numerals.
 #[doc = include_str!("./previews/Hebrew.md")]
@@ -6,10 +35,10 @@ pub struct Hebrew;
 
 impl<T> NominalSystem<T> for Hebrew
 where
-    T: Nominal + UnsignedInteger + TryFrom<u32> + From<u8>,
+    T: UnsignedInteger + TryFrom<u32>,
 {
     fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
-        let mut remaining = nominal;
+        let mut remaining = nominal.clone();
         if remaining.is_zero() {
             return Err(Error::NoZeroSymbol);
         }
@@ -18,40 +47,21 @@ where
         let sixteen = T::from(16);
 
         let mut formatted = NominalString::default();
-        'symbol_loop: for (symbol, value) in [
-            ('ת', 400u32),
-            ('ש', 300),
-            ('ר', 200),
-            ('ק', 100),
-            ('צ', 90),
-            ('פ', 80),
-            ('ע', 70),
-            ('ס', 60),
-            ('נ', 50),
-            ('מ', 40),
-            ('ל', 30),
-            ('כ', 20),
-            ('י', 10),
-            ('ט', 9),
-            ('ח', 8),
-            ('ז', 7),
-            ('ו', 6),
-            ('ה', 5),
-            ('ד', 4),
-            ('ג', 3),
-            ('ב', 2),
-            ('א', 1),
-        ] {
+        'symbol_loop: for &(symbol, value) in &SYMBOLS {
             let Ok(value) = T::try_from(value) else {
                 continue;
             };
 
             while remaining >= value {
                 if remaining == fifteen {
-                    formatted.try_push_str("ט״ו").with_nominal(nominal)?;
+                    formatted
+                        .try_push_str("ט״ו")
+                        .with_nominal(nominal.clone())?;
                     break 'symbol_loop;
                 } else if remaining == sixteen {
-                    formatted.try_push_str("ט״ז").with_nominal(nominal)?;
+                    formatted
+                        .try_push_str("ט״ז")
+                        .with_nominal(nominal.clone())?;
                     break 'symbol_loop;
                 }
 
@@ -60,12 +70,12 @@ where
                 // distinguish it from a word.
                 let single_symbol = value == remaining && formatted.is_empty();
                 if single_symbol {
-                    formatted.try_push('׳').with_nominal(nominal)?;
+                    formatted.try_push('׳').with_nominal(nominal.clone())?;
                 }
-                remaining -= value;
-                formatted.try_push(symbol).with_nominal(nominal)?;
+                remaining -= value.clone();
+                formatted.try_push(symbol).with_nominal(nominal.clone())?;
                 if single_symbol {
-                    formatted.try_push('״').with_nominal(nominal)?;
+                    formatted.try_push('״').with_nominal(nominal.clone())?;
                     break;
                 }
             }
@@ -75,6 +85,29 @@ where
     }
 }
 
+impl NominalSuccession for Hebrew {
+    fn next_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        step_parsed(nominal, true, parse_hebrew, |value| {
+            self.try_format_nominal(value)
+        })
+    }
+
+    fn prev_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        step_parsed(nominal, false, parse_hebrew, |value| {
+            self.try_format_nominal(value)
+        })
+    }
+}
+
+fn parse_hebrew(nominal: &str) -> Option<u128> {
+    let mut value = 0_u128;
+    for ch in nominal.chars().filter(|ch| !matches!(ch, '׳' | '״')) {
+        let (_, symbol_value) = SYMBOLS.iter().find(|(symbol, _)| *symbol == ch)?;
+        value = value.checked_add(u128::from(*symbol_value))?;
+    }
+    Some(value)
+}
+
 #[test]
 fn hebrew() {
     assert_eq!(Hebrew.format_nominal(997_u32), "תתקצז");
@@ -82,4 +115,21 @@ fn hebrew() {
     assert_eq!(Hebrew.format_nominal(15_u32), "ט״ו");
     assert_eq!(Hebrew.format_nominal(16_u32), "ט״ז");
     assert_eq!(Hebrew.try_format_nominal(0_u32), Err(Error::NoZeroSymbol));
+
+    assert_eq!(Hebrew.next_nominal("יד").unwrap(), "ט״ו");
+    assert_eq!(Hebrew.prev_nominal("ט״ז").unwrap(), "ט״ו");
+    assert_eq!(
+        Hebrew.prev_nominal("׳א״"),
+        Err(SuccessionError::NoPredecessor)
+    );
+    assert_eq!(
+        Hebrew.next_nominal("יה"),
+        Err(SuccessionError::InvalidNominal)
+    );
+    for n in 1_u32..2_000 {
+        let formatted = Hebrew.format_nominal(n);
+        let next = Hebrew.format_nominal(n + 1);
+        assert_eq!(Hebrew.next_nominal(&formatted).unwrap(), next, "{n}");
+        assert_eq!(Hebrew.prev_nominal(&next).unwrap(), formatted, "{n}");
+    }
 }
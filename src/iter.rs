@@ -0,0 +1,251 @@
+use core::iter::FusedIterator;
+use core::ops::{Bound, RangeBounds};
+
+use crate::{NominalString, NominalSystem, UnsignedInteger, UnwrapOrDecimal};
+
+/// An extension trait that pairs each item of an iterator with a nominal.
+pub trait EnumerateNominal: Iterator + Sized {
+    /// Returns an iterator that yields each item alongside a nominal formatted
+    /// using `system`, starting with `start`.
+    ///
+    /// This is similar to [`Iterator::enumerate`], except each index is
+    /// formatted using `system`. Nominals that cannot be formatted by `system`
+    /// are formatted using [`Decimal`](crate::Decimal). Iteration stops early
+    /// if the next index cannot be represented by `T`.
+    ///
+    /// ```rust
+    /// use nominals::{EnumerateNominal, RomanLower};
+    ///
+    /// let items = ["apples", "bananas", "cherries"]
+    ///     .into_iter()
+    ///     .enumerate_nominal(&RomanLower, 1_u32)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     items,
+    ///     [
+    ///         ("i".into(), "apples"),
+    ///         ("ii".into(), "bananas"),
+    ///         ("iii".into(), "cherries"),
+    ///     ]
+    /// );
+    /// ```
+    fn enumerate_nominal<S, T>(self, system: &S, start: T) -> EnumerateNominals<'_, Self, S, T>
+    where
+        S: NominalSystem<T>,
+        T: UnsignedInteger,
+    {
+        EnumerateNominals {
+            iter: self,
+            counting: Counting::new(system, Some(start)),
+        }
+    }
+}
+
+impl<I> EnumerateNominal for I where I: Iterator {}
+
+/// An iterator that yields the items of another iterator alongside formatted
+/// nominals.
+///
+/// This type is returned from [`EnumerateNominal::enumerate_nominal`].
+#[derive(Debug, Clone)]
+pub struct EnumerateNominals<'a, I, S, T> {
+    iter: I,
+    counting: Counting<'a, S, T>,
+}
+
+impl<I, S, T> Iterator for EnumerateNominals<'_, I, S, T>
+where
+    I: Iterator,
+    S: NominalSystem<T>,
+    T: UnsignedInteger,
+{
+    type Item = (NominalString, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.counting.next.as_ref()?;
+        let item = self.iter.next()?;
+        let (nominal, _) = self.counting.advance()?;
+        Some((nominal, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.counting.next.is_some() {
+            // The nominal could overflow before the inner iterator is
+            // exhausted.
+            (0, self.iter.size_hint().1)
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+/// An iterator over a range of nominals.
+///
+/// This type is returned from [`NominalSystem::iter`].
+#[derive(Debug, Clone)]
+pub struct Nominals<'a, S, T> {
+    counting: Counting<'a, S, T>,
+    end: Bound<T>,
+}
+
+impl<'a, S, T> Nominals<'a, S, T>
+where
+    S: NominalSystem<T>,
+    T: UnsignedInteger,
+{
+    pub(crate) fn new<R>(system: &'a S, range: R) -> Self
+    where
+        R: RangeBounds<T>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(start) => Some(start.clone()),
+            Bound::Excluded(start) => start.checked_add(&T::from(1)),
+            Bound::Unbounded => Some(T::from(0)),
+        };
+        Self {
+            counting: Counting::new(system, start),
+            end: range.end_bound().cloned(),
+        }
+    }
+}
+
+impl<S, T> Iterator for Nominals<'_, S, T>
+where
+    S: NominalSystem<T>,
+    T: UnsignedInteger,
+{
+    type Item = (NominalString, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.counting.next.as_ref()?;
+        let in_range = match &self.end {
+            Bound::Included(end) => next <= end,
+            Bound::Excluded(end) => next < end,
+            Bound::Unbounded => true,
+        };
+        if in_range {
+            self.counting.advance()
+        } else {
+            self.counting.next = None;
+            None
+        }
+    }
+}
+
+impl<S, T> FusedIterator for Nominals<'_, S, T>
+where
+    S: NominalSystem<T>,
+    T: UnsignedInteger,
+{
+}
+
+/// Formats consecutive nominals, deriving each nominal from the previously
+/// formatted nominal when possible.
+#[derive(Debug, Clone)]
+struct Counting<'a, S, T> {
+    system: &'a S,
+    next: Option<T>,
+    previous: Option<NominalString>,
+}
+
+impl<'a, S, T> Counting<'a, S, T>
+where
+    S: NominalSystem<T>,
+    T: UnsignedInteger,
+{
+    const fn new(system: &'a S, start: Option<T>) -> Self {
+        Self {
+            system,
+            next: start,
+            previous: None,
+        }
+    }
+
+    fn advance(&mut self) -> Option<(NominalString, T)> {
+        let nominal = self.next.take()?;
+        self.next = nominal.checked_add(&T::from(1));
+
+        let formatted = match &self.previous {
+            Some(previous) => self.system.try_format_successor(previous, nominal.clone()),
+            None => self.system.try_format_nominal(nominal.clone()),
+        };
+        // Only nominals formatted by the system can be used to derive the next
+        // nominal.
+        self.previous = formatted.as_ref().ok().cloned();
+        Some((formatted.unwrap_or_decimal(), nominal))
+    }
+}
+
+#[test]
+fn successors() {
+    use crate::{Decimal, DigitCollection, HexLower, LetterLower, LetterUpper, RomanUpper};
+
+    fn assert_successors<S>(system: &S, start: u32)
+    where
+        S: NominalSystem<u32>,
+    {
+        for (formatted, nominal) in system.iter(start..start + 2_000) {
+            assert_eq!(formatted, system.format_nominal(nominal), "{nominal}");
+        }
+    }
+
+    assert_successors(&Decimal, 0);
+    assert_successors(&HexLower, 0);
+    assert_successors(&LetterLower, 0);
+    assert_successors(&LetterUpper.one_based(), 0);
+    assert_successors(&RomanUpper, 0);
+    assert_successors(&Decimal, u32::MAX - 2_000);
+
+    // Successors are derived from the previous nominal's digits.
+    assert_eq!(Decimal.try_format_successor("1299", 0_u32).unwrap(), "1300");
+    assert_eq!(
+        LetterLower.try_format_successor("azz", 0_u32).unwrap(),
+        "baa"
+    );
+    assert_eq!(
+        LetterLower.try_format_successor("zz", 0_u32).unwrap(),
+        "aaa"
+    );
+    assert_eq!(HexLower.try_format_successor("ff", 0_u32).unwrap(), "100");
+}
+
+#[test]
+fn ranges() {
+    use alloc::vec::Vec;
+
+    use crate::{CircledNumber, Decimal, DigitCollection, LetterUpper};
+
+    let letters = LetterUpper.one_based();
+    let nominals = letters.iter(..3_u8).map(|(n, _)| n).collect::<Vec<_>>();
+    assert_eq!(nominals, ["0", "A", "B"]);
+    let nominals = letters.iter(25_u8..=27).map(|(n, _)| n).collect::<Vec<_>>();
+    assert_eq!(nominals, ["Y", "Z", "AA"]);
+
+    let nominals = CircledNumber
+        .iter(49_u8..=51)
+        .map(|(n, _)| n)
+        .collect::<Vec<_>>();
+    assert_eq!(nominals, ["㊾", "㊿", "51"]);
+
+    let nominals = Decimal.iter(253_u8..).collect::<Vec<_>>();
+    assert_eq!(
+        nominals,
+        [
+            ("253".into(), 253),
+            ("254".into(), 254),
+            ("255".into(), 255)
+        ]
+    );
+    assert_eq!(
+        Decimal
+            .iter((Bound::Excluded(u8::MAX), Bound::Unbounded))
+            .next(),
+        None
+    );
+
+    let items = "xyz"
+        .chars()
+        .enumerate_nominal(&Decimal, 254_u8)
+        .collect::<Vec<_>>();
+    assert_eq!(items, [("254".into(), 'x'), ("255".into(), 'y')]);
+}
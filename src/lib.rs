@@ -7,38 +7,66 @@ extern crate alloc;
 
 mod additive;
 mod chinese;
+mod counter;
+mod english;
 mod ethiopic;
 mod hebrew;
+mod iter;
 mod nominalstring;
+mod ordinal;
+mod outline;
+mod pattern;
+mod roman;
+mod romanize;
+mod succession;
+
+#[cfg(feature = "bigint")]
+mod bigint;
+#[cfg(feature = "alloc")]
+mod rbnf;
+#[cfg(feature = "alloc")]
+mod spellout;
+#[cfg(feature = "alloc")]
+mod tree;
 
 /// Systems that using a finite ordered set of nominals,
 /// without the option to compose multi character nominals.
 mod enumerated;
 
-mod sealed {
-    /// A trait that marks a type as performing integer-style division with its
-    /// `Div` and `Rem` implementations.
-    pub trait IntegerDivision {}
-}
-
 /// Systems that operate using ordered sets of digit-like characters.
 mod digital;
 
 use core::fmt::Debug;
-use core::ops::{Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+use core::ops::{Div, DivAssign, Mul, MulAssign, RangeBounds, Rem, RemAssign, Sub, SubAssign};
 
 pub use additive::*;
 pub use chinese::*;
+pub use counter::Counter;
 pub use digital::*;
+pub use english::{EnglishCardinal, EnglishOrdinal};
 pub use enumerated::*;
 pub use ethiopic::*;
 pub use hebrew::Hebrew;
+pub use iter::{EnumerateNominal, EnumerateNominals, Nominals};
 pub use nominalstring::{NominalString, OutOfMemoryError};
+pub use ordinal::*;
+pub use outline::*;
+pub use pattern::*;
+#[cfg(feature = "alloc")]
+pub use rbnf::{RuleBasedSystem, RuleError, RuleErrorKind};
+pub use roman::*;
+pub use romanize::{Hepburn, Pinyin, RevisedRomanization};
+#[cfg(feature = "alloc")]
+pub use spellout::{SpelloutCardinal, SpelloutLanguage, SpelloutOrdinal};
+pub use succession::{NominalSuccession, SuccessionError};
+#[cfg(feature = "alloc")]
+pub use tree::{CounterInstruction, CounterTree};
 
 /// A system of ordered nominal identifiers.
 pub trait NominalSystem<T>
 where
-    T: Nominal,
+    T: UnsignedInteger,
 {
     /// Formats `nominal` using this system.
     fn format_nominal(&self, nominal: T) -> NominalString {
@@ -53,36 +81,85 @@ where
     /// type is [`Error`], and each variant describes why formatting a nominal
     /// may fail.
     fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>>;
+
+    /// Tries to format `nominal`, whose predecessor was formatted as
+    /// `previous` by this system.
+    ///
+    /// Systems can override this function to derive the next nominal from
+    /// `previous` rather than formatting `nominal` from scratch. The default
+    /// implementation ignores `previous` and calls
+    /// [`try_format_nominal()`](Self::try_format_nominal).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as
+    /// [`try_format_nominal()`](Self::try_format_nominal).
+    fn try_format_successor(&self, previous: &str, nominal: T) -> Result<NominalString, Error<T>> {
+        let _ = previous;
+        self.try_format_nominal(nominal)
+    }
+
+    /// Returns an iterator over each nominal in `range`, yielding each
+    /// formatted nominal alongside its value.
+    ///
+    /// ```rust
+    /// use nominals::{LetterLower, NominalSystem};
+    ///
+    /// let mut nominals = LetterLower.iter(24_u32..=26);
+    /// assert_eq!(nominals.next(), Some(("y".into(), 24)));
+    /// assert_eq!(nominals.next(), Some(("z".into(), 25)));
+    /// assert_eq!(nominals.next(), Some(("aa".into(), 26)));
+    /// assert_eq!(nominals.next(), None);
+    /// ```
+    fn iter<R>(&self, range: R) -> Nominals<'_, Self, T>
+    where
+        Self: Sized,
+        R: RangeBounds<T>,
+    {
+        Nominals::new(self, range)
+    }
 }
 
 #[cfg(feature = "alloc")]
 impl<T> NominalSystem<T> for alloc::boxed::Box<dyn NominalSystem<T>>
 where
-    T: Nominal,
+    T: UnsignedInteger,
 {
     fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
         self.as_ref().try_format_nominal(nominal)
     }
+
+    fn try_format_successor(&self, previous: &str, nominal: T) -> Result<NominalString, Error<T>> {
+        self.as_ref().try_format_successor(previous, nominal)
+    }
 }
 
 #[cfg(feature = "alloc")]
 impl<T> NominalSystem<T> for alloc::rc::Rc<dyn NominalSystem<T>>
 where
-    T: Nominal,
+    T: UnsignedInteger,
 {
     fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
         self.as_ref().try_format_nominal(nominal)
     }
+
+    fn try_format_successor(&self, previous: &str, nominal: T) -> Result<NominalString, Error<T>> {
+        self.as_ref().try_format_successor(previous, nominal)
+    }
 }
 
 #[cfg(feature = "alloc")]
 impl<T> NominalSystem<T> for alloc::sync::Arc<dyn NominalSystem<T>>
 where
-    T: Nominal,
+    T: UnsignedInteger,
 {
     fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
         self.as_ref().try_format_nominal(nominal)
     }
+
+    fn try_format_successor(&self, previous: &str, nominal: T) -> Result<NominalString, Error<T>> {
+        self.as_ref().try_format_successor(previous, nominal)
+    }
 }
 
 #[test]
@@ -96,13 +173,39 @@ fn boxing() {
 }
 
 /// A type that can be formatted with a [`NominalSystem`].
-pub trait Nominal: UnsignedInteger {
+///
+/// This trait is implemented for every [`UnsignedInteger`], and for the
+/// `NonZero` unsigned integer types as `Nominal<T>` of their integer type `T`.
+/// Types that wrap an integer, such as a list index newtype, can implement this
+/// trait to be formatted directly:
+///
+/// ```rust
+/// use nominals::{DigitCollection, LetterLower, Nominal};
+///
+/// #[derive(Clone, Copy)]
+/// struct ItemIndex(u32);
+///
+/// impl Nominal<u32> for ItemIndex {
+///     fn into_integer(self) -> u32 {
+///         self.0
+///     }
+/// }
+///
+/// assert_eq!(ItemIndex(3).to_nominal(&LetterLower.one_based()), "c");
+/// ```
+pub trait Nominal<T = Self>: Sized
+where
+    T: UnsignedInteger,
+{
+    /// Returns the integer that represents this nominal.
+    fn into_integer(self) -> T;
+
     /// Returns `self` formatted as a nominal identifier using `system`.
     fn to_nominal<N>(self, system: &N) -> NominalString
     where
-        N: NominalSystem<Self>,
+        N: NominalSystem<T> + ?Sized,
     {
-        system.format_nominal(self)
+        system.format_nominal(self.into_integer())
     }
 
     /// Tries to format `self` as a nominal identifier using `system`.
@@ -112,22 +215,66 @@ pub trait Nominal: UnsignedInteger {
     /// Each nominal system can use its own error type. The crate-level error
     /// type is [`Error`], and each variant describes why formatting a nominal
     /// may fail.
-    fn try_to_nominal<N>(self, system: &N) -> Result<NominalString, Error<Self>>
+    fn try_to_nominal<N>(self, system: &N) -> Result<NominalString, Error<T>>
     where
-        N: NominalSystem<Self> + ?Sized,
+        N: NominalSystem<T> + ?Sized,
     {
-        system.try_format_nominal(self)
+        system.try_format_nominal(self.into_integer())
     }
 }
 
-impl Nominal for u8 {}
-impl Nominal for u16 {}
-impl Nominal for u32 {}
-impl Nominal for u64 {}
-impl Nominal for u128 {}
-impl Nominal for usize {}
+impl<T> Nominal for T
+where
+    T: UnsignedInteger,
+{
+    fn into_integer(self) -> T {
+        self
+    }
+}
+
+macro_rules! impl_non_zero_nominal {
+    ($type:ident, $integer:ident) => {
+        impl Nominal<$integer> for $type {
+            fn into_integer(self) -> $integer {
+                self.get()
+            }
+        }
+    };
+}
+
+impl_non_zero_nominal!(NonZeroU8, u8);
+impl_non_zero_nominal!(NonZeroU16, u16);
+impl_non_zero_nominal!(NonZeroU32, u32);
+impl_non_zero_nominal!(NonZeroU64, u64);
+impl_non_zero_nominal!(NonZeroU128, u128);
+impl_non_zero_nominal!(NonZeroUsize, usize);
+
+#[test]
+fn non_zero() {
+    let one = NonZeroU32::new(1).expect("not zero");
+    assert_eq!(one.to_nominal(&LetterLower.one_based()), "a");
+    assert_eq!(one.to_nominal(&Hebrew), "׳א״");
+    let max = NonZeroU128::new(u128::MAX).expect("not zero");
+    assert_eq!(
+        max.try_to_nominal(&Decimal),
+        Ok(u128::MAX.to_nominal(&Decimal))
+    );
+}
+
+/// A marker trait indicating that a type performs integer-style division with
+/// its [`Div`] and [`Rem`] implementations.
+///
+/// Implementing this trait is a promise that division truncates towards zero
+/// and that the remainder is always less than the divisor. Nominal systems rely
+/// on these properties to extract digits.
+pub trait IntegerDivision {}
 
 /// An unsigned integer type.
+///
+/// Nominal systems perform their arithmetic using this trait. It is
+/// implemented for all of Rust's unsigned integer types, and for
+/// `num_bigint::BigUint` when the `bigint` feature is enabled. Custom integer
+/// types can implement this trait after implementing [`IntegerDivision`].
 pub trait UnsignedInteger:
     Ord
     + From<u8>
@@ -139,33 +286,50 @@ pub trait UnsignedInteger:
     + DivAssign
     + MulAssign
     + RemAssign
-    + Copy
+    + Clone
     + Sized
-    + sealed::IntegerDivision
+    + IntegerDivision
     + TryFrom<usize>
     + TryInto<usize>
 {
     /// Returns true if `self` is 0.
-    fn is_zero(self) -> bool;
+    fn is_zero(&self) -> bool;
 
     /// Casts `self` as a [`usize`].
     ///
     /// This function should only be invoked when it is guaranteed the value is
     /// within the range of a [`usize`]. Otherwise, using fallible operations and
     /// returning an [`Error::OutOfBounds`] is preferred.
-    fn as_usize(self) -> usize;
+    fn as_usize(&self) -> usize;
+
+    /// Returns `self + other`, or `None` if the sum cannot be represented by
+    /// this type.
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    /// Returns `self * other`, or `None` if the product cannot be represented
+    /// by this type.
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
 }
 
 macro_rules! impl_positive_integer {
     ($type:ident) => {
-        impl sealed::IntegerDivision for $type {}
+        impl IntegerDivision for $type {}
         impl UnsignedInteger for $type {
-            fn is_zero(self) -> bool {
-                self == 0
+            fn is_zero(&self) -> bool {
+                *self == 0
+            }
+
+            #[allow(clippy::cast_possible_truncation)]
+            fn as_usize(&self) -> usize {
+                *self as usize
+            }
+
+            fn checked_add(&self, other: &Self) -> Option<Self> {
+                <$type>::checked_add(*self, *other)
             }
 
-            fn as_usize(self) -> usize {
-                self as usize
+            fn checked_mul(&self, other: &Self) -> Option<Self> {
+                <$type>::checked_mul(*self, *other)
             }
         }
     };
@@ -193,7 +357,7 @@ pub enum Error<T> {
 
 impl<T> Error<T>
 where
-    T: Nominal,
+    T: UnsignedInteger,
 {
     /// Converts this error to a nominal string in decimal form.
     ///
@@ -220,7 +384,7 @@ pub trait UnwrapOrDecimal {
 
 impl<T> UnwrapOrDecimal for Result<NominalString, Error<T>>
 where
-    T: Nominal,
+    T: UnsignedInteger,
 {
     fn unwrap_or_decimal(self) -> NominalString {
         match self {
@@ -11,8 +11,7 @@ use core::ops::{Deref, DerefMut};
 /// This type can store up to 47 bytes on the stack before requiring a heap
 /// allocation. The total size of this structure is 64 bytes on a 64-bit
 /// architecture.
-#[derive(Debug)]
-#[cfg_attr(feature = "alloc", derive(Clone))]
+#[derive(Debug, Clone)]
 pub struct NominalString(MaybeInline);
 
 impl NominalString {
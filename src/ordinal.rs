@@ -0,0 +1,566 @@
+use crate::{Error, NominalString, NominalSystem, UnsignedInteger, WithNominal};
+
+/// English ordinal numbers, such as `1st`, `2nd`, `3rd`, and `11th`.
+///
+/// Each nominal is formatted using the wrapped system, and the suffix is
+/// chosen based on the nominal's value. This allows any system to be used to
+/// format the number itself.
+///
+/// ```rust
+/// use nominals::{Decimal, Nominal, OrdinalEnglish};
+///
+/// let ordinal = OrdinalEnglish::new(Decimal);
+/// assert_eq!(1_u32.to_nominal(&ordinal), "1st");
+/// assert_eq!(12_u32.to_nominal(&ordinal), "12th");
+/// assert_eq!(23_u32.to_nominal(&ordinal), "23rd");
+/// assert_eq!(1_u32.to_nominal(&ordinal.superscript()), "1ˢᵗ");
+/// ```
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct OrdinalEnglish<S> {
+    system: S,
+    superscript: bool,
+}
+
+impl<S> OrdinalEnglish<S> {
+    /// Returns English ordinals that format numbers using `system`.
+    pub const fn new(system: S) -> Self {
+        Self {
+            system,
+            superscript: false,
+        }
+    }
+
+    /// Returns a variation that writes the suffix using superscript modifier
+    /// letters, such as `1ˢᵗ` and `2ⁿᵈ`.
+    #[must_use]
+    pub const fn superscript(mut self) -> Self {
+        self.superscript = true;
+        self
+    }
+
+    /// Returns the system used to format numbers.
+    pub const fn system(&self) -> &S {
+        &self.system
+    }
+}
+
+impl<S, T> NominalSystem<T> for OrdinalEnglish<S>
+where
+    S: NominalSystem<T>,
+    T: UnsignedInteger,
+{
+    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+        let mut formatted = self.system.try_format_nominal(nominal.clone())?;
+
+        // 11, 12, and 13 use `th` despite ending in 1, 2, and 3.
+        let last_two = (nominal.clone() % T::from(100)).as_usize();
+        let suffix = match (last_two / 10, last_two % 10) {
+            (1, _) | (_, 0 | 4..=9) => ["th", "ᵗʰ"],
+            (_, 1) => ["st", "ˢᵗ"],
+            (_, 2) => ["nd", "ⁿᵈ"],
+            (_, _) => ["rd", "ʳᵈ"],
+        };
+        formatted
+            .try_push_str(suffix[usize::from(self.superscript)])
+            .with_nominal(nominal)?;
+
+        Ok(formatted)
+    }
+}
+
+/// Ordinal indicators that depend on a language and grammatical gender, such
+/// as `1º`, `1ª`, `1er`, `1.`, and `1-й`.
+///
+/// Each nominal is formatted using the wrapped system, and the indicator is
+/// chosen based on the nominal's value, the language, and the gender.
+///
+/// | Language   | Masculine     | Feminine      | Neuter        |
+/// |------------|---------------|---------------|---------------|
+/// | Spanish    | `1º`          | `1ª`          | `1º`          |
+/// | Portuguese | `1º`          | `1ª`          | `1º`          |
+/// | Italian    | `1º`          | `1ª`          | `1º`          |
+/// | French     | `1er`, `2e`   | `1re`, `2e`   | `1er`, `2e`   |
+/// | German     | `1.`          | `1.`          | `1.`          |
+/// | Russian    | `1-й`         | `1-я`         | `1-е`         |
+///
+/// ```rust
+/// use nominals::{Decimal, GrammaticalGender, Nominal, OrdinalIndicator, OrdinalLanguage};
+///
+/// let french = OrdinalIndicator::new(Decimal, OrdinalLanguage::French);
+/// assert_eq!(1_u32.to_nominal(&french), "1er");
+/// assert_eq!(2_u32.to_nominal(&french), "2e");
+/// let french = french.gender(GrammaticalGender::Feminine);
+/// assert_eq!(1_u32.to_nominal(&french), "1re");
+/// assert_eq!(1_u32.to_nominal(&french.superscript()), "1ʳᵉ");
+///
+/// let spanish = OrdinalIndicator::new(Decimal, OrdinalLanguage::Spanish);
+/// assert_eq!(3_u32.to_nominal(&spanish), "3º");
+/// let spanish = spanish.gender(GrammaticalGender::Feminine);
+/// assert_eq!(3_u32.to_nominal(&spanish), "3ª");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct OrdinalIndicator<S> {
+    system: S,
+    language: OrdinalLanguage,
+    gender: GrammaticalGender,
+    superscript: bool,
+}
+
+impl<S> OrdinalIndicator<S> {
+    /// Returns masculine ordinal indicators for `language` that format numbers
+    /// using `system`.
+    pub const fn new(system: S, language: OrdinalLanguage) -> Self {
+        Self {
+            system,
+            language,
+            gender: GrammaticalGender::Masculine,
+            superscript: false,
+        }
+    }
+
+    /// Returns a variation that uses the indicators for `gender`.
+    ///
+    /// Languages without an indicator for `gender` use their masculine
+    /// indicators.
+    #[must_use]
+    pub const fn gender(mut self, gender: GrammaticalGender) -> Self {
+        self.gender = gender;
+        self
+    }
+
+    /// Returns a variation that writes French indicators using superscript
+    /// modifier letters, such as `1ᵉʳ` and `2ᵉ`.
+    ///
+    /// The indicators of other languages are unaffected.
+    #[must_use]
+    pub const fn superscript(mut self) -> Self {
+        self.superscript = true;
+        self
+    }
+
+    /// Returns the system used to format numbers.
+    pub const fn system(&self) -> &S {
+        &self.system
+    }
+
+    /// Returns the language of the indicators.
+    pub const fn language(&self) -> OrdinalLanguage {
+        self.language
+    }
+
+    fn indicator(&self, is_one: bool) -> &'static str {
+        match (self.language, self.gender) {
+            (
+                OrdinalLanguage::Spanish | OrdinalLanguage::Portuguese | OrdinalLanguage::Italian,
+                GrammaticalGender::Feminine,
+            ) => "\u{AA}",
+            (
+                OrdinalLanguage::Spanish | OrdinalLanguage::Portuguese | OrdinalLanguage::Italian,
+                _,
+            ) => "\u{BA}",
+            // In French, only premier and première use a distinct indicator.
+            (OrdinalLanguage::French, GrammaticalGender::Feminine) if is_one => {
+                ["re", "ʳᵉ"][usize::from(self.superscript)]
+            }
+            (OrdinalLanguage::French, _) if is_one => ["er", "ᵉʳ"][usize::from(self.superscript)],
+            (OrdinalLanguage::French, _) => ["e", "ᵉ"][usize::from(self.superscript)],
+            (OrdinalLanguage::German, _) => ".",
+            (OrdinalLanguage::Russian, GrammaticalGender::Masculine) => "-й",
+            (OrdinalLanguage::Russian, GrammaticalGender::Feminine) => "-я",
+            (OrdinalLanguage::Russian, GrammaticalGender::Neuter) => "-е",
+        }
+    }
+}
+
+impl<S, T> NominalSystem<T> for OrdinalIndicator<S>
+where
+    S: NominalSystem<T>,
+    T: UnsignedInteger,
+{
+    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+        let mut formatted = self.system.try_format_nominal(nominal.clone())?;
+        formatted
+            .try_push_str(self.indicator(nominal == T::from(1)))
+            .with_nominal(nominal)?;
+        Ok(formatted)
+    }
+}
+
+/// A language supported by [`OrdinalIndicator`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum OrdinalLanguage {
+    /// Spanish: `1º`, `1ª`.
+    Spanish,
+    /// Portuguese: `1º`, `1ª`.
+    Portuguese,
+    /// Italian: `1º`, `1ª`.
+    Italian,
+    /// French: `1er`, `1re`, `2e`.
+    French,
+    /// German: `1.`.
+    German,
+    /// Russian: `1-й`, `1-я`, `1-е`.
+    Russian,
+}
+
+/// The grammatical gender of the noun an ordinal describes.
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum GrammaticalGender {
+    /// Masculine gender.
+    #[default]
+    Masculine,
+    /// Feminine gender.
+    Feminine,
+    /// Neuter gender.
+    Neuter,
+}
+
+/// Chinese, Japanese, and Korean ordinal numbers, such as `第一`, `第3位`,
+/// `一番目`, and `제1장`.
+///
+/// Each nominal is formatted using the wrapped system, prefixed with the
+/// language's ordinal prefix and followed by an optional counter word.
+///
+/// | Language | Prefix | Example         |
+/// |----------|--------|-----------------|
+/// | Chinese  | `第`   | `第一章`        |
+/// | Japanese | `第`   | `第一`, `一番目` |
+/// | Korean   | `제`   | `제1장`         |
+///
+/// ```rust
+/// use nominals::{
+///     CjkLanguage, Decimal, JapaneseInformal, Nominal, OrdinalCjk, SimplifiedChineseInformal,
+/// };
+///
+/// let chapter =
+///     OrdinalCjk::new(SimplifiedChineseInformal::default(), CjkLanguage::Chinese).counter("章");
+/// assert_eq!(1_u32.to_nominal(&chapter), "第一章");
+/// let rank = OrdinalCjk::new(Decimal, CjkLanguage::Chinese).counter("位");
+/// assert_eq!(3_u32.to_nominal(&rank), "第3位");
+/// let japanese = OrdinalCjk::new(JapaneseInformal, CjkLanguage::Japanese)
+///     .without_prefix()
+///     .counter("番目");
+/// assert_eq!(1_u32.to_nominal(&japanese), "一番目");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct OrdinalCjk<S> {
+    system: S,
+    language: CjkLanguage,
+    prefix: bool,
+    counter: &'static str,
+}
+
+impl<S> OrdinalCjk<S> {
+    /// Returns ordinals for `language` that format numbers using `system`.
+    pub const fn new(system: S, language: CjkLanguage) -> Self {
+        Self {
+            system,
+            language,
+            prefix: true,
+            counter: "",
+        }
+    }
+
+    /// Returns a variation that writes `counter` after each number, such as
+    /// `章` (chapter), `位` (rank), `番目`, or `장`.
+    #[must_use]
+    pub const fn counter(mut self, counter: &'static str) -> Self {
+        self.counter = counter;
+        self
+    }
+
+    /// Returns a variation that omits the ordinal prefix, for counter words
+    /// that are ordinal by themselves, such as `番目` and `번째`.
+    #[must_use]
+    pub const fn without_prefix(mut self) -> Self {
+        self.prefix = false;
+        self
+    }
+
+    /// Returns the system used to format numbers.
+    pub const fn system(&self) -> &S {
+        &self.system
+    }
+
+    /// Returns the language of the ordinals.
+    pub const fn language(&self) -> CjkLanguage {
+        self.language
+    }
+}
+
+impl<S, T> NominalSystem<T> for OrdinalCjk<S>
+where
+    S: NominalSystem<T>,
+    T: UnsignedInteger,
+{
+    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+        let mut formatted = self.system.try_format_nominal(nominal.clone())?;
+        if self.prefix {
+            let prefix = match self.language {
+                CjkLanguage::Chinese | CjkLanguage::Japanese => '第',
+                CjkLanguage::Korean => '제',
+            };
+            formatted
+                .try_push_front(prefix)
+                .with_nominal(nominal.clone())?;
+        }
+        formatted.try_push_str(self.counter).with_nominal(nominal)?;
+        Ok(formatted)
+    }
+}
+
+/// A language supported by [`OrdinalCjk`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum CjkLanguage {
+    /// Chinese: `第一`.
+    Chinese,
+    /// Japanese: `第一`.
+    Japanese,
+    /// Korean: `제일`.
+    Korean,
+}
+
+/// Native Korean ordinal numbers, such as `첫째`, `둘째`, and `스물한째`.
+///
+/// Native Korean numbers only exist from 1 through 99. Sino-Korean ordinals,
+/// such as `제일`, can be formatted by wrapping [`HangeulFormal`] in
+/// [`OrdinalCjk`].
+///
+/// ```rust
+/// use nominals::{KoreanNativeOrdinal, Nominal};
+///
+/// assert_eq!(1_u32.to_nominal(&KoreanNativeOrdinal), "첫째");
+/// assert_eq!(12_u32.to_nominal(&KoreanNativeOrdinal), "열두째");
+/// assert_eq!(20_u32.to_nominal(&KoreanNativeOrdinal), "스무째");
+/// ```
+///
+/// [`HangeulFormal`]: crate::HangeulFormal
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct KoreanNativeOrdinal;
+
+impl<T> NominalSystem<T> for KoreanNativeOrdinal
+where
+    T: UnsignedInteger,
+{
+    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+        const ONES: [&str; 10] = [
+            "",
+            "첫째",
+            "둘째",
+            "셋째",
+            "넷째",
+            "다섯째",
+            "여섯째",
+            "일곱째",
+            "여덟째",
+            "아홉째",
+        ];
+        const TENS: [&str; 10] = [
+            "", "열", "스물", "서른", "마흔", "쉰", "예순", "일흔", "여든", "아흔",
+        ];
+
+        if nominal.is_zero() {
+            return Err(Error::NoZeroSymbol);
+        } else if nominal >= T::from(100) {
+            return Err(Error::OutOfBounds(nominal));
+        }
+
+        let value = nominal.as_usize();
+        let (tens, ones) = (value / 10, value % 10);
+        let mut formatted = NominalString::new();
+        // One and two are shortened after tens (열한째, 스물두째), and twenty is
+        // shortened when it ends the number (스무째).
+        let result = match (tens, ones) {
+            (2, 0) => formatted.try_push_str("스무째"),
+            (_, 0) => formatted
+                .try_push_str(TENS[tens])
+                .and_then(|()| formatted.try_push('째')),
+            (0, _) => formatted.try_push_str(ONES[ones]),
+            (_, 1) => formatted
+                .try_push_str(TENS[tens])
+                .and_then(|()| formatted.try_push_str("한째")),
+            (_, 2) => formatted
+                .try_push_str(TENS[tens])
+                .and_then(|()| formatted.try_push_str("두째")),
+            _ => formatted
+                .try_push_str(TENS[tens])
+                .and_then(|()| formatted.try_push_str(ONES[ones])),
+        };
+        result.with_nominal(nominal)?;
+        Ok(formatted)
+    }
+}
+
+#[test]
+fn english() {
+    use crate::{Decimal, EasternArabic, Nominal};
+
+    let ordinal = OrdinalEnglish::new(Decimal);
+    for (n, expected) in [
+        (0_u32, "0th"),
+        (1, "1st"),
+        (2, "2nd"),
+        (3, "3rd"),
+        (4, "4th"),
+        (10, "10th"),
+        (11, "11th"),
+        (12, "12th"),
+        (13, "13th"),
+        (21, "21st"),
+        (22, "22nd"),
+        (101, "101st"),
+        (111, "111th"),
+        (112, "112th"),
+        (1_013, "1013th"),
+    ] {
+        assert_eq!(n.to_nominal(&ordinal), expected);
+    }
+
+    assert_eq!(
+        u128::MAX.to_nominal(&ordinal),
+        "340282366920938463463374607431768211455th"
+    );
+    assert_eq!(
+        (u128::MAX - 4).to_nominal(&ordinal),
+        "340282366920938463463374607431768211451st"
+    );
+    assert_eq!(u8::MAX.to_nominal(&ordinal), "255th");
+    assert_eq!(253_u8.to_nominal(&ordinal), "253rd");
+
+    let superscript = ordinal.superscript();
+    assert_eq!(2_u32.to_nominal(&superscript), "2ⁿᵈ");
+    assert_eq!(3_u32.to_nominal(&superscript), "3ʳᵈ");
+    assert_eq!(11_u32.to_nominal(&superscript), "11ᵗʰ");
+    assert_eq!(
+        22_u32.to_nominal(&OrdinalEnglish::new(EasternArabic)),
+        "٢٢nd"
+    );
+}
+
+#[test]
+fn indicators() {
+    use crate::{Decimal, DigitCollection, LetterLower, Nominal};
+
+    let expectations = [
+        (
+            OrdinalLanguage::Spanish,
+            ["1º", "1ª", "1º"],
+            ["21º", "21ª", "21º"],
+        ),
+        (
+            OrdinalLanguage::Portuguese,
+            ["1º", "1ª", "1º"],
+            ["21º", "21ª", "21º"],
+        ),
+        (
+            OrdinalLanguage::Italian,
+            ["1º", "1ª", "1º"],
+            ["21º", "21ª", "21º"],
+        ),
+        (
+            OrdinalLanguage::French,
+            ["1er", "1re", "1er"],
+            ["21e", "21e", "21e"],
+        ),
+        (
+            OrdinalLanguage::German,
+            ["1.", "1.", "1."],
+            ["21.", "21.", "21."],
+        ),
+        (
+            OrdinalLanguage::Russian,
+            ["1-й", "1-я", "1-е"],
+            ["21-й", "21-я", "21-е"],
+        ),
+    ];
+    for (language, first, twenty_first) in expectations {
+        for (index, gender) in [
+            GrammaticalGender::Masculine,
+            GrammaticalGender::Feminine,
+            GrammaticalGender::Neuter,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let indicator = OrdinalIndicator::new(Decimal, language).gender(gender);
+            assert_eq!(1_u32.to_nominal(&indicator), first[index]);
+            assert_eq!(21_u32.to_nominal(&indicator), twenty_first[index]);
+        }
+    }
+
+    let french = OrdinalIndicator::new(Decimal, OrdinalLanguage::French).superscript();
+    assert_eq!(1_u32.to_nominal(&french), "1ᵉʳ");
+    assert_eq!(0_u32.to_nominal(&french), "0ᵉ");
+    assert_eq!(
+        u128::MAX.to_nominal(&french),
+        "340282366920938463463374607431768211455ᵉ"
+    );
+    let russian = OrdinalIndicator::new(Decimal, OrdinalLanguage::Russian).superscript();
+    assert_eq!(3_u32.to_nominal(&russian), "3-й");
+
+    let letters = OrdinalIndicator::new(LetterLower.one_based(), OrdinalLanguage::German);
+    assert_eq!(2_u32.to_nominal(&letters), "b.");
+    assert_eq!(0_u32.try_to_nominal(&letters), Err(Error::NoZeroSymbol));
+}
+
+#[test]
+fn cjk() {
+    use crate::{Decimal, HangeulFormal, JapaneseFormal, Nominal, TraditionalChineseInformal};
+
+    let chinese = OrdinalCjk::new(TraditionalChineseInformal::default(), CjkLanguage::Chinese);
+    assert_eq!(1_u32.to_nominal(&chinese), "第一");
+    assert_eq!(23_u32.to_nominal(&chinese.counter("章")), "第二十三章");
+    assert_eq!(
+        3_u32.to_nominal(&OrdinalCjk::new(Decimal, CjkLanguage::Chinese).counter("位")),
+        "第3位"
+    );
+
+    let japanese = OrdinalCjk::new(JapaneseFormal, CjkLanguage::Japanese);
+    assert_eq!(2_u32.to_nominal(&japanese), "第弐");
+    assert_eq!(
+        2_u32.to_nominal(&japanese.without_prefix().counter("番目")),
+        "弐番目"
+    );
+
+    let korean = OrdinalCjk::new(HangeulFormal::default(), CjkLanguage::Korean);
+    assert_eq!(1_u32.to_nominal(&korean), "제일");
+    assert_eq!(
+        1_u32.to_nominal(&OrdinalCjk::new(Decimal, CjkLanguage::Korean).counter("장")),
+        "제1장"
+    );
+    assert_eq!(
+        1_u32.to_nominal(
+            &OrdinalCjk::new(KoreanNativeOrdinal, CjkLanguage::Korean).without_prefix()
+        ),
+        "첫째"
+    );
+
+    for (n, expected) in [
+        (1_u32, "첫째"),
+        (2, "둘째"),
+        (3, "셋째"),
+        (4, "넷째"),
+        (9, "아홉째"),
+        (10, "열째"),
+        (11, "열한째"),
+        (12, "열두째"),
+        (13, "열셋째"),
+        (20, "스무째"),
+        (21, "스물한째"),
+        (30, "서른째"),
+        (45, "마흔다섯째"),
+        (99, "아흔아홉째"),
+    ] {
+        assert_eq!(n.to_nominal(&KoreanNativeOrdinal), expected);
+    }
+    assert_eq!(
+        0_u32.try_to_nominal(&KoreanNativeOrdinal),
+        Err(Error::NoZeroSymbol)
+    );
+    assert_eq!(
+        100_u32.try_to_nominal(&KoreanNativeOrdinal),
+        Err(Error::OutOfBounds(100))
+    );
+}
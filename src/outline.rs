@@ -0,0 +1,214 @@
+use crate::{
+    Decimal, Error, LetterLower, NominalString, NominalSystem, OneBased, RomanLower,
+    UnsignedInteger, UnwrapOrDecimal, WithNominal,
+};
+
+const ONE_BASED_LETTER_LOWER: OneBased<LetterLower> = OneBased::new(LetterLower);
+
+/// A hierarchical numbering system that formats one nominal per level, such as
+/// `1.a.iii`.
+///
+/// This type implements the semantics of the CSS `counters()` function: each
+/// index in a slice of indices is formatted from the outermost level to the
+/// innermost level, and each formatted level is joined using a separator.
+/// Unlike `counters()`, each level can use its own [`NominalSystem`] and
+/// separator.
+///
+/// When an outline contains more levels than systems or separators, the
+/// systems and separators are reused from the start. This matches the rotation
+/// many user agents use for nested lists.
+///
+/// ```rust
+/// use nominals::{Decimal, Outline, RomanUpper};
+///
+/// let outline = Outline::<u32>::ordered();
+/// assert_eq!(outline.format(&[1, 1, 3]), "1.a.iii");
+/// assert_eq!(outline.format(&[2, 3, 1, 4]), "2.c.i.4");
+///
+/// let outline = Outline::new(&[&RomanUpper, &Decimal]).separators(&[" - ", "."]);
+/// assert_eq!(outline.format(&[4_u32, 2, 1]), "IV - 2.I");
+/// ```
+#[derive(Clone, Copy)]
+pub struct Outline<'a, T> {
+    systems: &'a [&'a dyn NominalSystem<T>],
+    separators: &'a [&'a str],
+}
+
+impl<'a, T> Outline<'a, T>
+where
+    T: UnsignedInteger,
+{
+    /// Returns an outline that formats each level using `systems` and joins
+    /// each level with `.`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `systems` is empty.
+    #[must_use]
+    pub const fn new(systems: &'a [&'a dyn NominalSystem<T>]) -> Self {
+        assert!(!systems.is_empty());
+        Self {
+            systems,
+            separators: &["."],
+        }
+    }
+
+    /// Returns this outline, using `separators` to join each level.
+    ///
+    /// The first separator is placed between the first and second levels, the
+    /// second separator between the second and third levels, and so on. If
+    /// `separators` is empty, levels are formatted without separators.
+    #[must_use]
+    pub const fn separators(mut self, separators: &'a [&'a str]) -> Self {
+        self.separators = separators;
+        self
+    }
+
+    /// Formats `indices` as a single nominal, starting with the outermost
+    /// level.
+    ///
+    /// Any level that cannot be formatted by its system is formatted using
+    /// [`Decimal`] instead. If the outline cannot be stored, the index of the
+    /// level being joined is returned in [`Decimal`], matching
+    /// [`NominalSystem::format_nominal`].
+    #[must_use]
+    pub fn format(&self, indices: &[T]) -> NominalString {
+        // Formatting a level with `format_nominal` cannot fail, so an error
+        // can only come from joining the levels.
+        self.format_levels(indices, |system, index| {
+            Ok(system.format_nominal(index.clone()))
+        })
+        .unwrap_or_decimal()
+    }
+
+    /// Tries to format `indices` as a single nominal, starting with the
+    /// outermost level.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned from a level's system, or
+    /// [`Error::OutOfMemory`] if the formatted outline cannot be stored.
+    pub fn try_format(&self, indices: &[T]) -> Result<NominalString, Error<T>> {
+        self.format_levels(indices, |system, index| {
+            system.try_format_nominal(index.clone())
+        })
+    }
+
+    /// Returns the system used to format `level`, where `0` is the outermost
+    /// level.
+    #[must_use]
+    pub fn level_system(&self, level: usize) -> &'a dyn NominalSystem<T> {
+        self.systems[level % self.systems.len()]
+    }
+
+    fn format_levels(
+        &self,
+        indices: &[T],
+        mut format_level: impl FnMut(&dyn NominalSystem<T>, &T) -> Result<NominalString, Error<T>>,
+    ) -> Result<NominalString, Error<T>> {
+        let mut formatted = NominalString::new();
+        for (level, index) in indices.iter().enumerate() {
+            if level > 0 && !self.separators.is_empty() {
+                formatted
+                    .try_push_str(self.separators[(level - 1) % self.separators.len()])
+                    .with_nominal(index.clone())?;
+            }
+            formatted
+                .try_push_str(&format_level(self.level_system(level), index)?)
+                .with_nominal(index.clone())?;
+        }
+        Ok(formatted)
+    }
+}
+
+impl<T> Outline<'static, T>
+where
+    T: UnsignedInteger + TryFrom<u128>,
+{
+    /// Returns an outline for nested ordered lists, counting from 1.
+    ///
+    /// Levels rotate through [`Decimal`], [`LetterLower`], and
+    /// [`RomanLower`], producing outlines like `1.a.iii`.
+    #[must_use]
+    pub const fn ordered() -> Self {
+        Self::new(&[&Decimal, &ONE_BASED_LETTER_LOWER, &RomanLower])
+    }
+}
+
+impl<T> Outline<'static, T>
+where
+    T: UnsignedInteger,
+{
+    /// Returns an outline for nested unordered lists.
+    ///
+    /// Levels rotate through [`Disc`], [`Circle`], and [`Square`]. Unordered
+    /// lists typically only display the innermost level, which can be
+    /// formatted using the system returned from [`Outline::level_system`].
+    #[must_use]
+    pub const fn unordered() -> Self {
+        Self::new(&[&Disc, &Circle, &Square])
+    }
+}
+
+macro_rules! impl_symbol {
+    ($(#$doc:tt)* $name:ident, $symbol:expr) => {
+        $(#$doc)*
+        #[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+        pub struct $name;
+
+        impl<T> NominalSystem<T> for $name
+        where
+            T: UnsignedInteger,
+        {
+            fn try_format_nominal(&self, _nominal: T) -> Result<NominalString, Error<T>> {
+                Ok(NominalString::from($symbol))
+            }
+        }
+    };
+}
+
+impl_symbol!(
+    /// A bullet (`•`) that is used for every nominal.
+    Disc,
+    '\u{2022}'
+);
+
+impl_symbol!(
+    /// A white bullet (`◦`) that is used for every nominal.
+    Circle,
+    '\u{25E6}'
+);
+
+impl_symbol!(
+    /// A black small square (`▪`) that is used for every nominal.
+    Square,
+    '\u{25AA}'
+);
+
+#[test]
+fn outline() {
+    use crate::{DigitCollection, LetterUpper, RomanUpper};
+
+    let ordered = Outline::<u32>::ordered();
+    assert_eq!(ordered.format(&[]), "");
+    assert_eq!(ordered.format(&[1]), "1");
+    assert_eq!(ordered.format(&[1, 1]), "1.a");
+    assert_eq!(ordered.format(&[1, 1, 3]), "1.a.iii");
+    assert_eq!(ordered.format(&[1, 27, 4, 10]), "1.aa.iv.10");
+    assert_eq!(ordered.try_format(&[1, 0]), Err(Error::NoZeroSymbol));
+    assert_eq!(ordered.format(&[1, 0]), "1.0");
+
+    let letters = LetterUpper.one_based();
+    let systems: [&dyn NominalSystem<u32>; 3] = [&RomanUpper, &letters, &Decimal];
+    let legal = Outline::new(&systems).separators(&[". ", ".", ""]);
+    assert_eq!(legal.format(&[2_u32, 3, 4, 5]), "II. C.4V");
+
+    let joined = Outline::new(&[&Decimal]).separators(&[]);
+    assert_eq!(joined.format(&[1_u32, 2, 3]), "123");
+
+    let unordered = Outline::<u32>::unordered();
+    assert_eq!(unordered.level_system(0).format_nominal(1), "•");
+    assert_eq!(unordered.level_system(1).format_nominal(2), "◦");
+    assert_eq!(unordered.level_system(2).format_nominal(3), "▪");
+    assert_eq!(unordered.level_system(3).format_nominal(4), "•");
+}
@@ -0,0 +1,400 @@
+use core::slice;
+
+use crate::{
+    CircledNumber, Decimal, DigitCollection, DoubleCircledNumber, Error, FootnoteSymbol,
+    GreekLower, GreekUpper, HangeulJamo, HangeulSyllable, Hebrew, Hiragana, HiraganaIroha,
+    Katakana, KatakanaIroha, LetterLower, LetterUpper, NominalString, NominalSystem, RomanLower,
+    RomanUpper, SimplifiedChineseInformal, UnsignedInteger, UnwrapOrDecimal, WithNominal,
+};
+
+/// A numbering pattern, such as `1.a)`, `(I.i)`, or `第一章`.
+///
+/// This type implements the numbering pattern language of
+/// [Typst](https://typst.app/docs/reference/model/numbering/). Each
+/// [`CountingSymbol`] in the pattern is replaced with a nominal, and all other
+/// text is preserved:
+///
+/// - Text before the first counting symbol is a prefix.
+/// - Text after the last counting symbol is a suffix.
+/// - Text before any other counting symbol is a separator.
+///
+/// When formatting more levels than the pattern has counting symbols, the last
+/// counting symbol and the separator before it are repeated. If the last
+/// counting symbol has no separator before it, the suffix is used as the
+/// separator. When formatting fewer levels, the remaining counting symbols and
+/// separators are omitted.
+///
+/// ```rust
+/// use nominals::NumberingPattern;
+///
+/// let pattern = NumberingPattern::new("1.a)").expect("valid pattern");
+/// assert_eq!(pattern.format(&[1_u32]), "1)");
+/// assert_eq!(pattern.format(&[1_u32, 2]), "1.b)");
+/// assert_eq!(pattern.format(&[1_u32, 2, 3]), "1.b.c)");
+///
+/// let pattern = NumberingPattern::new("(I.i)").expect("valid pattern");
+/// assert_eq!(pattern.format(&[4_u32, 9]), "(IV.ix)");
+///
+/// let pattern = NumberingPattern::new("1.").expect("valid pattern");
+/// assert_eq!(pattern.format(&[1_u32, 2]), "1.2.");
+///
+/// let pattern = NumberingPattern::new("第一章").expect("valid pattern");
+/// assert_eq!(pattern.format(&[12_u32]), "第十二章");
+/// ```
+///
+/// Letters that are counting symbols, such as `a` and `I`, are only counting
+/// symbols when they are not next to another letter. This preserves words such
+/// as `Chapter` and `Section`:
+///
+/// ```rust
+/// use nominals::NumberingPattern;
+///
+/// let pattern = NumberingPattern::new("Chapter I, Section 1").expect("valid pattern");
+/// assert_eq!(pattern.format(&[2_u32, 3]), "Chapter II, Section 3");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct NumberingPattern<'a> {
+    pattern: &'a str,
+}
+
+impl<'a> NumberingPattern<'a> {
+    /// Returns a numbering pattern parsed from `pattern`, or `None` if
+    /// `pattern` contains no counting symbols.
+    #[must_use]
+    pub fn new(pattern: &'a str) -> Option<Self> {
+        let parsed = Self { pattern };
+        parsed.pieces().next()?;
+        Some(parsed)
+    }
+
+    /// Returns the text of this pattern.
+    #[must_use]
+    pub const fn as_str(&self) -> &'a str {
+        self.pattern
+    }
+
+    /// Formats `indices` using this pattern, starting with the outermost level.
+    ///
+    /// Any level that cannot be formatted by its counting symbol is formatted
+    /// using [`Decimal`] instead. If the pattern cannot be stored, the index
+    /// being formatted is returned in [`Decimal`], matching
+    /// [`NominalSystem::format_nominal`].
+    #[must_use]
+    pub fn format<T>(&self, indices: &[T]) -> NominalString
+    where
+        T: UnsignedInteger + TryFrom<u32> + TryFrom<u128>,
+    {
+        // Levels fall back to `Decimal` individually, which leaves running out
+        // of memory as the only error.
+        self.format_levels(indices, |symbol, index| {
+            Ok(symbol.format_nominal(index.clone()))
+        })
+        .unwrap_or_decimal()
+    }
+
+    /// Tries to format `indices` using this pattern, starting with the
+    /// outermost level.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned from a level's counting symbol, or
+    /// [`Error::OutOfMemory`] if the formatted nominal cannot be stored.
+    pub fn try_format<T>(&self, indices: &[T]) -> Result<NominalString, Error<T>>
+    where
+        T: UnsignedInteger + TryFrom<u32> + TryFrom<u128>,
+    {
+        self.format_levels(indices, |symbol, index| {
+            symbol.try_format_nominal(index.clone())
+        })
+    }
+
+    fn format_levels<T>(
+        &self,
+        indices: &[T],
+        mut format_level: impl FnMut(CountingSymbol, &T) -> Result<NominalString, Error<T>>,
+    ) -> Result<NominalString, Error<T>>
+    where
+        T: UnsignedInteger,
+    {
+        let mut pieces = self.pieces();
+        let mut last_piece = None;
+        let mut formatted = NominalString::new();
+        for index in indices {
+            let (separator, symbol) = if let Some(piece) = pieces.next() {
+                last_piece = Some(piece);
+                piece
+            } else {
+                let (separator, symbol) = last_piece.expect("at least one counting symbol");
+                if separator.is_empty() {
+                    (self.suffix(), symbol)
+                } else {
+                    (separator, symbol)
+                }
+            };
+            formatted
+                .try_push_str(separator)
+                .with_nominal(index.clone())?;
+            formatted
+                .try_push_str(&format_level(symbol, index)?)
+                .with_nominal(index.clone())?;
+        }
+
+        if let Some(index) = indices.last() {
+            formatted
+                .try_push_str(self.suffix())
+                .with_nominal(index.clone())?;
+            Ok(formatted)
+        } else {
+            Ok(NominalString::from(self.suffix()))
+        }
+    }
+
+    fn pieces(&self) -> Pieces<'a> {
+        Pieces {
+            pattern: self.pattern,
+            offset: 0,
+        }
+    }
+
+    fn suffix(&self) -> &'a str {
+        let mut pieces = self.pieces();
+        while pieces.next().is_some() {}
+        &self.pattern[pieces.offset..]
+    }
+}
+
+impl<T> NominalSystem<T> for NumberingPattern<'_>
+where
+    T: UnsignedInteger + TryFrom<u32> + TryFrom<u128>,
+{
+    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+        self.try_format(slice::from_ref(&nominal))
+    }
+}
+
+/// An iterator over the separators and counting symbols of a pattern.
+struct Pieces<'a> {
+    pattern: &'a str,
+    offset: usize,
+}
+
+impl<'a> Iterator for Pieces<'a> {
+    type Item = (&'a str, CountingSymbol);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut previous = self.pattern[..self.offset].chars().next_back();
+        let mut chars = self.pattern[self.offset..].char_indices().peekable();
+        while let Some((offset, ch)) = chars.next() {
+            let next = chars.peek().map(|&(_, next)| next);
+            let next_to_letter = previous.map_or(false, char::is_alphabetic)
+                || next.map_or(false, char::is_alphabetic);
+            previous = Some(ch);
+            let Some(symbol) = CountingSymbol::from_char(ch) else {
+                continue;
+            };
+            if symbol.is_letter() && next_to_letter {
+                continue;
+            }
+
+            let start = self.offset;
+            self.offset += offset + ch.len_utf8();
+            return Some((&self.pattern[start..start + offset], symbol));
+        }
+
+        None
+    }
+}
+
+/// A character in a [`NumberingPattern`] that is replaced with a nominal.
+///
+/// Each counting symbol is also a [`NominalSystem`]. All systems count from 1.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum CountingSymbol {
+    /// `1`: [`Decimal`]
+    Decimal,
+    /// `a`: [`LetterLower`]
+    LetterLower,
+    /// `A`: [`LetterUpper`]
+    LetterUpper,
+    /// `i`: [`RomanLower`]
+    RomanLower,
+    /// `I`: [`RomanUpper`]
+    RomanUpper,
+    /// `α`: [`GreekLower`]
+    GreekLower,
+    /// `Α`: [`GreekUpper`]
+    GreekUpper,
+    /// `一`: [`SimplifiedChineseInformal`]
+    SimplifiedChinese,
+    /// `壹`: [`SimplifiedChineseInformal`] using financial characters
+    SimplifiedChineseFinancial,
+    /// `あ`: [`Hiragana`]
+    Hiragana,
+    /// `い`: [`HiraganaIroha`]
+    HiraganaIroha,
+    /// `ア`: [`Katakana`]
+    Katakana,
+    /// `イ`: [`KatakanaIroha`]
+    KatakanaIroha,
+    /// `א`: [`Hebrew`]
+    Hebrew,
+    /// `ㄱ`: [`HangeulJamo`]
+    HangeulJamo,
+    /// `가`: [`HangeulSyllable`]
+    HangeulSyllable,
+    /// `①`: [`CircledNumber`]
+    CircledNumber,
+    /// `⓵`: [`DoubleCircledNumber`]
+    DoubleCircledNumber,
+    /// `*`: [`FootnoteSymbol`]
+    FootnoteSymbol,
+}
+
+impl CountingSymbol {
+    /// Returns the counting symbol represented by `ch`, if any.
+    #[must_use]
+    pub const fn from_char(ch: char) -> Option<Self> {
+        Some(match ch {
+            '1' => Self::Decimal,
+            'a' => Self::LetterLower,
+            'A' => Self::LetterUpper,
+            'i' => Self::RomanLower,
+            'I' => Self::RomanUpper,
+            'α' => Self::GreekLower,
+            'Α' => Self::GreekUpper,
+            '一' => Self::SimplifiedChinese,
+            '壹' => Self::SimplifiedChineseFinancial,
+            'あ' => Self::Hiragana,
+            'い' => Self::HiraganaIroha,
+            'ア' => Self::Katakana,
+            'イ' => Self::KatakanaIroha,
+            'א' => Self::Hebrew,
+            'ㄱ' => Self::HangeulJamo,
+            '가' => Self::HangeulSyllable,
+            '①' => Self::CircledNumber,
+            '⓵' => Self::DoubleCircledNumber,
+            '*' => Self::FootnoteSymbol,
+            _ => return None,
+        })
+    }
+
+    /// Returns true if this counting symbol is a Latin or Greek letter, which
+    /// is treated as text when it is next to another letter.
+    const fn is_letter(self) -> bool {
+        matches!(
+            self,
+            Self::LetterLower
+                | Self::LetterUpper
+                | Self::RomanLower
+                | Self::RomanUpper
+                | Self::GreekLower
+                | Self::GreekUpper
+        )
+    }
+
+    /// Returns the character that represents this counting symbol.
+    #[must_use]
+    pub const fn as_char(self) -> char {
+        match self {
+            Self::Decimal => '1',
+            Self::LetterLower => 'a',
+            Self::LetterUpper => 'A',
+            Self::RomanLower => 'i',
+            Self::RomanUpper => 'I',
+            Self::GreekLower => 'α',
+            Self::GreekUpper => 'Α',
+            Self::SimplifiedChinese => '一',
+            Self::SimplifiedChineseFinancial => '壹',
+            Self::Hiragana => 'あ',
+            Self::HiraganaIroha => 'い',
+            Self::Katakana => 'ア',
+            Self::KatakanaIroha => 'イ',
+            Self::Hebrew => 'א',
+            Self::HangeulJamo => 'ㄱ',
+            Self::HangeulSyllable => '가',
+            Self::CircledNumber => '①',
+            Self::DoubleCircledNumber => '⓵',
+            Self::FootnoteSymbol => '*',
+        }
+    }
+}
+
+impl<T> NominalSystem<T> for CountingSymbol
+where
+    T: UnsignedInteger + TryFrom<u32> + TryFrom<u128>,
+{
+    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+        match self {
+            Self::Decimal => Decimal.try_format_nominal(nominal),
+            Self::LetterLower => LetterLower.one_based().try_format_nominal(nominal),
+            Self::LetterUpper => LetterUpper.one_based().try_format_nominal(nominal),
+            Self::RomanLower => RomanLower.try_format_nominal(nominal),
+            Self::RomanUpper => RomanUpper.try_format_nominal(nominal),
+            Self::GreekLower => GreekLower.one_based().try_format_nominal(nominal),
+            Self::GreekUpper => GreekUpper.one_based().try_format_nominal(nominal),
+            Self::SimplifiedChinese => {
+                SimplifiedChineseInformal::default().try_format_nominal(nominal)
+            }
+            Self::SimplifiedChineseFinancial => SimplifiedChineseInformal::default()
+                .financial()
+                .try_format_nominal(nominal),
+            Self::Hiragana => Hiragana.one_based().try_format_nominal(nominal),
+            Self::HiraganaIroha => HiraganaIroha.one_based().try_format_nominal(nominal),
+            Self::Katakana => Katakana.one_based().try_format_nominal(nominal),
+            Self::KatakanaIroha => KatakanaIroha.one_based().try_format_nominal(nominal),
+            Self::Hebrew => Hebrew.try_format_nominal(nominal),
+            Self::HangeulJamo => HangeulJamo.one_based().try_format_nominal(nominal),
+            Self::HangeulSyllable => HangeulSyllable.one_based().try_format_nominal(nominal),
+            Self::CircledNumber => CircledNumber.try_format_nominal(nominal),
+            Self::DoubleCircledNumber => DoubleCircledNumber.try_format_nominal(nominal),
+            Self::FootnoteSymbol => FootnoteSymbol.try_format_nominal(nominal),
+        }
+    }
+}
+
+#[test]
+fn patterns() {
+    use crate::Nominal;
+
+    assert_eq!(NumberingPattern::new("§ "), None);
+    assert_eq!(NumberingPattern::new(""), None);
+
+    let pattern = NumberingPattern::new("1.a)").expect("valid");
+    assert_eq!(pattern.format::<u32>(&[]), ")");
+    assert_eq!(pattern.format(&[3_u32]), "3)");
+    assert_eq!(pattern.format(&[3_u32, 27]), "3.aa)");
+    assert_eq!(pattern.format(&[3_u32, 1, 2, 3]), "3.a.b.c)");
+    assert_eq!(pattern.try_format(&[1_u32, 0]), Err(Error::NoZeroSymbol));
+    assert_eq!(pattern.format(&[1_u32, 0]), "1.0)");
+
+    let pattern = NumberingPattern::new("(I.i)").expect("valid");
+    assert_eq!(pattern.format(&[2_u32]), "(II)");
+    assert_eq!(pattern.format(&[2_u32, 3, 4]), "(II.iii.iv)");
+    assert_eq!(4_u32.to_nominal(&pattern), "(IV)");
+
+    let pattern = NumberingPattern::new("Section 1").expect("valid");
+    assert_eq!(pattern.format(&[2_u32]), "Section 2");
+    let pattern = NumberingPattern::new("Appendix A: Part i").expect("valid");
+    assert_eq!(pattern.format(&[3_u32, 4]), "Appendix C: Part iv");
+    assert_eq!(NumberingPattern::new("Chapter"), None);
+
+    let pattern = NumberingPattern::new("§1 ").expect("valid");
+    assert_eq!(pattern.format(&[1_u32, 2]), "§1§2 ");
+
+    let pattern = NumberingPattern::new("第一章 壹").expect("valid");
+    assert_eq!(pattern.format(&[21_u32, 2]), "第二十一章 贰");
+
+    let pattern = NumberingPattern::new("あ-イ-α-Α-א-가-ㄱ-①-⓵-*").expect("valid");
+    assert_eq!(
+        pattern.format(&[1_u32, 2, 3, 4, 5, 6, 7, 8, 9, 10]),
+        "あ-ロ-γ-Δ-׳ה״-바-ㅅ-⑧-⓽-§§"
+    );
+    assert_eq!(pattern.as_str(), "あ-イ-α-Α-א-가-ㄱ-①-⓵-*");
+
+    for ch in "1aAiIα Α一壹あいアイא가ㄱ①⓵*".chars() {
+        if let Some(symbol) = CountingSymbol::from_char(ch) {
+            assert_eq!(symbol.as_char(), ch);
+        }
+    }
+}
@@ -0,0 +1,775 @@
+use alloc::vec::Vec;
+
+use crate::{Decimal, Error, NominalString, NominalSystem, UnsignedInteger};
+
+/// Rule sets can refer to each other or themselves, so recursion is limited
+/// to prevent malformed rules from looping forever.
+const MAX_DEPTH: usize = 64;
+
+/// A nominal system defined by [ICU rule-based number format][rbnf] rules.
+///
+/// Rule text consists of one or more rule sets. Each rule set begins with its
+/// name, such as `%spellout-cardinal:`, followed by rules separated by `;`.
+/// Rule sets whose names begin with `%%` are private and can only be used by
+/// other rule sets.
+///
+/// Each rule has a descriptor followed by the rule's text:
+///
+/// - `100:` sets the rule's base value. Grouping characters (`,` and `.`) are
+///   ignored, allowing base values like `1,000,000`.
+/// - `100/1000:` sets the rule's base value and radix. The default radix is
+///   10\. The rule's divisor is the largest power of the radix that is less
+///   than or equal to the base value.
+/// - `100>:` divides the rule's divisor by its radix once per `>`.
+/// - Rules without a descriptor have a base value one greater than the
+///   preceding rule.
+/// - Negative (`-x`), fraction (`x.x`, `0.x`, `x.0`), `Inf`, and `NaN` rules
+///   are ignored, as nominals are never negative or fractional.
+///
+/// A nominal is formatted using the rule with the largest base value that is
+/// less than or equal to the nominal. Within each rule's text, substitutions
+/// are replaced:
+///
+/// - `←←` or `<<`: The nominal divided by the rule's divisor.
+/// - `→→` or `>>`: The remainder of the nominal divided by the rule's divisor.
+/// - `→→→` or `>>>`: The remainder, formatted using the preceding rule rather
+///   than the rule set's best matching rule.
+/// - `==`: The nominal itself.
+///
+/// Each substitution is formatted using the rule's rule set, unless another
+/// rule set is named inside the substitution (`←%%tens←`) or a decimal pattern
+/// is specified (`=#,##0=`). Text inside of brackets (`[ →→]`) is omitted when
+/// the nominal is a multiple of the rule's divisor. A leading apostrophe in a
+/// rule's text is removed, allowing rule text to begin with whitespace.
+///
+/// ```rust
+/// use nominals::{Nominal, RuleBasedSystem};
+///
+/// let rules = RuleBasedSystem::parse(
+///     "%spellout:
+///         0: zero; 1: one; 2: two; 3: three; 4: four;
+///         5: five; 6: six; 7: seven; 8: eight; 9: nine;
+///         10: ten; 11: eleven; 12: twelve; 13: →%%teen→teen;
+///         20: twenty[-→→]; 30: thirty[-→→];
+///         100: ←← hundred[ →→];
+///      %%teen:
+///         3: thir; 4: four; 5: fif; 6: six; 7: seven; 8: eigh; 9: nine;",
+/// )
+/// .unwrap();
+/// assert_eq!(13_u32.to_nominal(&rules), "thirteen");
+/// assert_eq!(18_u32.to_nominal(&rules), "eighteen");
+/// assert_eq!(321_u32.to_nominal(&rules), "three hundred twenty-one");
+/// ```
+///
+/// [rbnf]: https://unicode-org.github.io/icu/userguide/format_parse/numbers/rbnf.html
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RuleBasedSystem<'a> {
+    rule_sets: Vec<RuleSet<'a>>,
+    rule_set: usize,
+}
+
+impl<'a> RuleBasedSystem<'a> {
+    /// Parses `rules` as ICU rule-based number format rules.
+    ///
+    /// The last public rule set is used to format nominals. A different rule
+    /// set can be selected using [`RuleBasedSystem::with_rule_set`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RuleError`] describing the first problem found in `rules`.
+    pub fn parse(rules: &'a str) -> Result<Self, RuleError> {
+        let mut rule_sets = Vec::<RuleSet<'a>>::new();
+        for chunk in rules.split(';') {
+            let mut chunk = chunk.trim_start();
+            if chunk.is_empty() {
+                continue;
+            }
+
+            if chunk.starts_with('%') {
+                let colon = chunk
+                    .find(':')
+                    .filter(|&colon| !chunk[..colon].trim_end().contains(char::is_whitespace))
+                    .ok_or_else(|| RuleError::new(rules, chunk, RuleErrorKind::InvalidRuleSet))?;
+                let name = chunk[..colon].trim_end();
+                if rule_sets.iter().any(|set| set.name == name) {
+                    return Err(RuleError::new(
+                        rules,
+                        chunk,
+                        RuleErrorKind::DuplicateRuleSet,
+                    ));
+                }
+                rule_sets.push(RuleSet {
+                    name,
+                    rules: Vec::new(),
+                });
+                chunk = chunk[colon + 1..].trim_start();
+                if chunk.is_empty() {
+                    continue;
+                }
+            } else if rule_sets.is_empty() {
+                // Rules without a rule set name form a single unnamed rule
+                // set.
+                rule_sets.push(RuleSet {
+                    name: "",
+                    rules: Vec::new(),
+                });
+            }
+
+            let rule_set = rule_sets.len() - 1;
+            // Lenient parsing rules describe how to parse formatted numbers,
+            // which this crate does not support.
+            if rule_sets[rule_set].name != "%%lenient-parse" {
+                parse_rule(rules, chunk, &mut rule_sets[rule_set])?;
+            }
+        }
+
+        // Validate each substitution that refers to another rule set.
+        for set in &rule_sets {
+            for rule in &set.rules {
+                for part in &rule.parts {
+                    if let PartKind::Substitution(_, Target::Set(name)) = part.kind {
+                        if !rule_sets.iter().any(|set| set.name == name) {
+                            return Err(RuleError::new(rules, name, RuleErrorKind::UnknownRuleSet));
+                        }
+                    }
+                }
+            }
+        }
+
+        let rule_set = rule_sets
+            .iter()
+            .rposition(RuleSet::is_public)
+            .ok_or(RuleError {
+                offset: 0,
+                kind: RuleErrorKind::Empty,
+            })?;
+        Ok(Self {
+            rule_sets,
+            rule_set,
+        })
+    }
+
+    /// Returns this system, formatting nominals using the rule set named
+    /// `name`, or `None` if no rule set with that name exists.
+    ///
+    /// `name` includes the leading `%`, such as `%spellout-ordinal`. Private
+    /// rule sets can be selected.
+    #[must_use]
+    pub fn with_rule_set(mut self, name: &str) -> Option<Self> {
+        self.rule_set = self.rule_sets.iter().position(|set| set.name == name)?;
+        Some(self)
+    }
+
+    /// Returns the name of the rule set used to format nominals.
+    #[must_use]
+    pub fn rule_set(&self) -> &'a str {
+        self.rule_sets[self.rule_set].name
+    }
+
+    /// Returns an iterator over the names of the public rule sets.
+    pub fn public_rule_sets(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.rule_sets
+            .iter()
+            .filter(|set| set.is_public())
+            .map(|set| set.name)
+    }
+
+    fn format_with_set<T>(
+        &self,
+        rule_set: usize,
+        value: &T,
+        formatted: &mut NominalString,
+        depth: usize,
+    ) -> Result<(), Failure>
+    where
+        T: UnsignedInteger + TryFrom<u128>,
+    {
+        let rules = &self.rule_sets[rule_set].rules;
+        let mut found = None;
+        for (index, rule) in rules.iter().enumerate() {
+            match T::try_from(rule.base) {
+                Ok(base) if base <= *value => found = Some(index),
+                _ => break,
+            }
+        }
+        let mut index = found.ok_or(Failure::OutOfBounds)?;
+
+        // When a rule's base value isn't a multiple of its divisor, multiples
+        // of the divisor are formatted by the preceding rule instead.
+        let rule = &rules[index];
+        if index > 0 && rule.has_remainder() && rule.base % rule.divisor != 0 {
+            if let Ok(divisor) = T::try_from(rule.divisor) {
+                if (value.clone() % divisor).is_zero() {
+                    index -= 1;
+                }
+            }
+        }
+
+        self.format_with_rule(rule_set, index, value, formatted, depth)
+    }
+
+    fn format_with_rule<T>(
+        &self,
+        rule_set: usize,
+        index: usize,
+        value: &T,
+        formatted: &mut NominalString,
+        depth: usize,
+    ) -> Result<(), Failure>
+    where
+        T: UnsignedInteger + TryFrom<u128>,
+    {
+        if depth > MAX_DEPTH {
+            return Err(Failure::OutOfBounds);
+        }
+
+        let rule = &self.rule_sets[rule_set].rules[index];
+        let divisor = T::try_from(rule.divisor).map_err(|_| Failure::OutOfBounds)?;
+        let remainder = value.clone() % divisor.clone();
+        for part in &rule.parts {
+            if part.optional && remainder.is_zero() {
+                continue;
+            }
+
+            match part.kind {
+                PartKind::Text(text) => formatted.try_push_str(text)?,
+                PartKind::Substitution(Substitution::PrecedingRule, _) => {
+                    self.format_with_rule(rule_set, index - 1, &remainder, formatted, depth + 1)?;
+                }
+                PartKind::Substitution(substitution, target) => {
+                    let operand = match substitution {
+                        Substitution::Quotient => value.clone() / divisor.clone(),
+                        Substitution::Remainder | Substitution::PrecedingRule => remainder.clone(),
+                        Substitution::Same => value.clone(),
+                    };
+                    match target {
+                        Target::SameSet => {
+                            self.format_with_set(rule_set, &operand, formatted, depth + 1)?;
+                        }
+                        Target::Set(name) => {
+                            let rule_set = self
+                                .rule_sets
+                                .iter()
+                                .position(|set| set.name == name)
+                                .ok_or(Failure::OutOfBounds)?;
+                            self.format_with_set(rule_set, &operand, formatted, depth + 1)?;
+                        }
+                        Target::Decimal { grouping } => {
+                            format_decimal(operand, grouping, formatted)?;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> NominalSystem<T> for RuleBasedSystem<'_>
+where
+    T: UnsignedInteger + TryFrom<u128>,
+{
+    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+        let mut formatted = NominalString::new();
+        match self.format_with_set(self.rule_set, &nominal, &mut formatted, 0) {
+            Ok(()) => Ok(formatted),
+            Err(Failure::OutOfBounds) => Err(Error::OutOfBounds(nominal)),
+            Err(Failure::OutOfMemory) => Err(Error::OutOfMemory(nominal)),
+        }
+    }
+}
+
+/// An error parsing rule-based number format rules.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct RuleError {
+    /// The byte offset in the rule text where the error was found.
+    pub offset: usize,
+    /// The kind of error.
+    pub kind: RuleErrorKind,
+}
+
+impl RuleError {
+    fn new(rules: &str, location: &str, kind: RuleErrorKind) -> Self {
+        Self {
+            offset: location.as_ptr() as usize - rules.as_ptr() as usize,
+            kind,
+        }
+    }
+}
+
+/// The kinds of errors that can be found in rule-based number format rules.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum RuleErrorKind {
+    /// The rules do not contain a public rule set.
+    Empty,
+    /// A rule set name contains whitespace or is not followed by `:`.
+    InvalidRuleSet,
+    /// Two rule sets share the same name.
+    DuplicateRuleSet,
+    /// A rule's descriptor is not valid.
+    InvalidDescriptor,
+    /// A rule's base value is not greater than the preceding rule's base
+    /// value.
+    OutOfOrder,
+    /// A substitution is missing its closing token.
+    UnterminatedSubstitution,
+    /// A substitution's rule set or pattern is not valid.
+    InvalidSubstitution,
+    /// A `[` is missing its matching `]`, or optional text is nested.
+    UnbalancedBrackets,
+    /// A substitution refers to a rule set that does not exist.
+    UnknownRuleSet,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct RuleSet<'a> {
+    name: &'a str,
+    rules: Vec<Rule<'a>>,
+}
+
+impl RuleSet<'_> {
+    fn is_public(&self) -> bool {
+        !self.name.starts_with("%%")
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Rule<'a> {
+    base: u128,
+    divisor: u128,
+    parts: Vec<Part<'a>>,
+}
+
+impl Rule<'_> {
+    fn has_remainder(&self) -> bool {
+        self.parts.iter().any(|part| {
+            matches!(
+                part.kind,
+                PartKind::Substitution(Substitution::Remainder | Substitution::PrecedingRule, _)
+            )
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct Part<'a> {
+    optional: bool,
+    kind: PartKind<'a>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum PartKind<'a> {
+    Text(&'a str),
+    Substitution(Substitution, Target<'a>),
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Substitution {
+    Quotient,
+    Remainder,
+    PrecedingRule,
+    Same,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Target<'a> {
+    SameSet,
+    Set(&'a str),
+    Decimal { grouping: bool },
+}
+
+enum Failure {
+    OutOfBounds,
+    OutOfMemory,
+}
+
+impl From<crate::OutOfMemoryError> for Failure {
+    fn from(_: crate::OutOfMemoryError) -> Self {
+        Self::OutOfMemory
+    }
+}
+
+fn parse_rule<'a>(
+    rules: &'a str,
+    chunk: &'a str,
+    rule_set: &mut RuleSet<'a>,
+) -> Result<(), RuleError> {
+    let previous = rule_set.rules.last().map(|rule| rule.base);
+    let (base, radix, decrements, body) = match chunk.split_once(':') {
+        Some((descriptor, body)) if is_descriptor(descriptor.trim()) => {
+            let descriptor = descriptor.trim();
+            let invalid = || RuleError::new(rules, chunk, RuleErrorKind::InvalidDescriptor);
+            if descriptor.contains(['x', 'X']) || matches!(descriptor, "Inf" | "NaN") {
+                // Negative and fractional rules are never used.
+                return Ok(());
+            }
+
+            let without_decrements = descriptor.trim_end_matches('>');
+            let decrements = descriptor.len() - without_decrements.len();
+            let (base, radix) = match without_decrements.split_once('/') {
+                Some((base, radix)) => (base, parse_base(radix).ok_or_else(invalid)?),
+                None => (without_decrements, 10),
+            };
+            let base = parse_base(base).ok_or_else(invalid)?;
+            if radix < 2 {
+                return Err(invalid());
+            }
+            (base, radix, decrements, body)
+        }
+        _ => (previous.map_or(0, |previous| previous + 1), 10, 0, chunk),
+    };
+    if previous.map_or(false, |previous| base <= previous) {
+        return Err(RuleError::new(rules, chunk, RuleErrorKind::OutOfOrder));
+    }
+
+    let mut exponent = 0_usize;
+    let mut power = 1_u128;
+    while let Some(next) = power.checked_mul(radix).filter(|next| *next <= base) {
+        power = next;
+        exponent += 1;
+    }
+    let mut divisor = 1_u128;
+    for _ in 0..exponent.saturating_sub(decrements) {
+        divisor *= radix;
+    }
+
+    let body = body.trim_start();
+    let body = body.strip_prefix('\'').unwrap_or(body);
+    let parts = parse_body(rules, body)?;
+    if previous.is_none() {
+        let uses_preceding = parts.iter().any(|part| {
+            matches!(
+                part.kind,
+                PartKind::Substitution(Substitution::PrecedingRule, _)
+            )
+        });
+        if uses_preceding {
+            return Err(RuleError::new(
+                rules,
+                chunk,
+                RuleErrorKind::InvalidSubstitution,
+            ));
+        }
+    }
+    rule_set.rules.push(Rule {
+        base,
+        divisor,
+        parts,
+    });
+    Ok(())
+}
+
+fn is_descriptor(descriptor: &str) -> bool {
+    !descriptor.is_empty()
+        && (matches!(descriptor, "Inf" | "NaN")
+            || descriptor
+                .chars()
+                .all(|ch| matches!(ch, '0'..='9' | ',' | '.' | '/' | '>' | 'x' | 'X' | '-')))
+}
+
+fn parse_base(base: &str) -> Option<u128> {
+    let mut value = 0_u128;
+    let mut has_digits = false;
+    for ch in base.chars().filter(|ch| !matches!(ch, ',' | '.')) {
+        let digit = ch.to_digit(10)?;
+        value = value.checked_mul(10)?.checked_add(u128::from(digit))?;
+        has_digits = true;
+    }
+    has_digits.then_some(value)
+}
+
+fn parse_body<'a>(rules: &'a str, body: &'a str) -> Result<Vec<Part<'a>>, RuleError> {
+    let mut parts = Vec::new();
+    let mut optional = false;
+    let mut text_start = 0;
+    let mut offset = 0;
+    while let Some(ch) = body[offset..].chars().next() {
+        let token_end = offset + ch.len_utf8();
+        let substitution = match ch {
+            '[' | ']' => None,
+            '<' | '←' => Some(Substitution::Quotient),
+            '>' | '→' => Some(Substitution::Remainder),
+            '=' => Some(Substitution::Same),
+            _ => {
+                offset = token_end;
+                continue;
+            }
+        };
+
+        if text_start < offset {
+            parts.push(Part {
+                optional,
+                kind: PartKind::Text(&body[text_start..offset]),
+            });
+        }
+
+        let Some(mut substitution) = substitution else {
+            if optional == (ch == '[') {
+                return Err(RuleError::new(
+                    rules,
+                    &body[offset..],
+                    RuleErrorKind::UnbalancedBrackets,
+                ));
+            }
+            optional = ch == '[';
+            offset = token_end;
+            text_start = offset;
+            continue;
+        };
+
+        let is_closing = |candidate: char| match substitution {
+            Substitution::Quotient => matches!(candidate, '<' | '←'),
+            Substitution::Remainder | Substitution::PrecedingRule => {
+                matches!(candidate, '>' | '→')
+            }
+            Substitution::Same => candidate == '=',
+        };
+        let descriptor_length = body[token_end..].find(is_closing).ok_or_else(|| {
+            RuleError::new(
+                rules,
+                &body[offset..],
+                RuleErrorKind::UnterminatedSubstitution,
+            )
+        })?;
+        let descriptor = &body[token_end..token_end + descriptor_length];
+        let closing = body[token_end + descriptor_length..]
+            .chars()
+            .next()
+            .map_or(0, char::len_utf8);
+        let mut end = token_end + descriptor_length + closing;
+
+        if substitution == Substitution::Remainder && descriptor.is_empty() {
+            if let Some(third) = body[end..].chars().next().filter(|ch| is_closing(*ch)) {
+                substitution = Substitution::PrecedingRule;
+                end += third.len_utf8();
+            }
+        }
+
+        let target = if descriptor.is_empty() {
+            Target::SameSet
+        } else if descriptor.starts_with('%') {
+            Target::Set(descriptor)
+        } else if descriptor.starts_with(['#', '0']) {
+            Target::Decimal {
+                grouping: descriptor.contains(','),
+            }
+        } else {
+            return Err(RuleError::new(
+                rules,
+                &body[offset..],
+                RuleErrorKind::InvalidSubstitution,
+            ));
+        };
+        parts.push(Part {
+            optional,
+            kind: PartKind::Substitution(substitution, target),
+        });
+        offset = end;
+        text_start = end;
+    }
+
+    if optional {
+        return Err(RuleError::new(
+            rules,
+            body,
+            RuleErrorKind::UnbalancedBrackets,
+        ));
+    }
+    if text_start < body.len() {
+        parts.push(Part {
+            optional,
+            kind: PartKind::Text(&body[text_start..]),
+        });
+    }
+    Ok(parts)
+}
+
+fn format_decimal<T>(value: T, grouping: bool, formatted: &mut NominalString) -> Result<(), Failure>
+where
+    T: UnsignedInteger,
+{
+    let digits = Decimal
+        .try_format_nominal(value)
+        .map_err(|_| Failure::OutOfMemory)?;
+    for (index, digit) in digits.chars().enumerate() {
+        if grouping && index > 0 && (digits.len() - index) % 3 == 0 {
+            formatted.try_push(',')?;
+        }
+        formatted.try_push(digit)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+const ENGLISH: &str = "
+%%lenient-parse:
+    &[last primary ignorable ] << ' ' << ',' << '-' << '\u{AD}';
+%spellout-cardinal:
+    -x: minus →→;
+    x.x: ←← point →→;
+    0: zero; 1: one; 2: two; 3: three; 4: four; 5: five; 6: six; 7: seven;
+    8: eight; 9: nine; 10: ten; 11: eleven; 12: twelve; 13: thirteen;
+    14: fourteen; 15: fifteen; 16: sixteen; 17: seventeen; 18: eighteen;
+    19: nineteen;
+    20: twenty[-→→]; 30: thirty[-→→]; 40: forty[-→→]; 50: fifty[-→→];
+    60: sixty[-→→]; 70: seventy[-→→]; 80: eighty[-→→]; 90: ninety[-→→];
+    100: ←← hundred[ →→];
+    1000: ←← thousand[ →→];
+    1,000,000: ←← million[ →→];
+    1,000,000,000: ←← billion[ →→];
+    1,000,000,000,000,000,000: =#,##0=;
+%%ord-th:
+    0: th;
+    1: ' =%spellout-ordinal=;
+%%ord-y:
+    0: ieth;
+    1: y-=%spellout-ordinal=;
+%spellout-ordinal:
+    0: zeroth; 1: first; 2: second; 3: third; 4: fourth; 5: fifth; 6: sixth;
+    7: seventh; 8: eighth; 9: ninth; 10: tenth; 11: eleventh; 12: twelfth;
+    13: =%spellout-cardinal=th;
+    20: twent→%%ord-y→; 30: thirt→%%ord-y→; 40: fort→%%ord-y→;
+    50: fift→%%ord-y→; 60: sixt→%%ord-y→; 70: sevent→%%ord-y→;
+    80: eight→%%ord-y→; 90: ninet→%%ord-y→;
+    100: ←%spellout-cardinal← hundred→%%ord-th→;
+    1000: ←%spellout-cardinal← thousand→%%ord-th→;
+    1,000,000: ←%spellout-cardinal← million→%%ord-th→;
+";
+
+#[test]
+fn english_rules() {
+    use crate::{EnglishCardinal, EnglishOrdinal, Nominal};
+
+    let rules = RuleBasedSystem::parse(ENGLISH).unwrap();
+    assert_eq!(rules.rule_set(), "%spellout-ordinal");
+    assert_eq!(
+        rules.public_rule_sets().collect::<Vec<_>>(),
+        ["%spellout-cardinal", "%spellout-ordinal"]
+    );
+    let cardinal = rules.clone().with_rule_set("%spellout-cardinal").unwrap();
+    assert!(rules.clone().with_rule_set("%missing").is_none());
+
+    for n in (0_u64..20_000).chain((0..2_000).map(|n| n * 7_919_993)) {
+        assert_eq!(
+            n.to_nominal(&cardinal),
+            n.to_nominal(&EnglishCardinal::default()),
+        );
+    }
+    for n in 0_u64..20_000 {
+        assert_eq!(
+            n.to_nominal(&rules),
+            n.to_nominal(&EnglishOrdinal::default()),
+        );
+    }
+    assert_eq!(
+        (u128::from(u64::MAX) * 1_000).to_nominal(&cardinal),
+        "18,446,744,073,709,551,615,000"
+    );
+}
+
+#[test]
+fn radix_rules() {
+    use crate::Nominal;
+
+    let hex = RuleBasedSystem::parse(
+        "0: 0; 1: 1; 2: 2; 3: 3; 4: 4; 5: 5; 6: 6; 7: 7; 8: 8; 9: 9;
+         a; b; c; d; e; f;
+         16/16: ←←→→;",
+    )
+    .unwrap();
+    assert_eq!(hex.rule_set(), "");
+    assert_eq!(0_u32.to_nominal(&hex), "0");
+    assert_eq!(15_u32.to_nominal(&hex), "f");
+    assert_eq!(255_u32.to_nominal(&hex), "ff");
+    assert_eq!(256_u32.to_nominal(&hex), "100");
+    assert_eq!(0xfeed_u32.to_nominal(&hex), "feed");
+
+    // Years are read in hundreds by reducing the divisor of the 1100 rule.
+    let years = RuleBasedSystem::parse(
+        "%%digits:
+            0: oh; 1: one; 2: two; 3: three; 4: four; 5: five; 6: six;
+            7: seven; 8: eight; 9: nine; 10: ten; 11: eleven; 12: twelve;
+            13: thirteen; 14: fourteen; 15: fifteen; 16: sixteen;
+            17: seventeen; 18: eighteen; 19: nineteen; 20: twenty[-→→];
+         %year:
+            0: =%%digits=;
+            1100>: ←%%digits← [→→→];
+            99: =%%digits=;
+        ",
+    );
+    assert_eq!(
+        years.unwrap_err().kind,
+        RuleErrorKind::OutOfOrder,
+        "99 follows 1100"
+    );
+    let years = RuleBasedSystem::parse(
+        "%%digits:
+            0: oh; 1: one; 2: two; 3: three; 4: four; 5: five; 6: six;
+            7: seven; 8: eight; 9: nine; 10: ten; 11: eleven; 12: twelve;
+            13: thirteen; 14: fourteen; 15: fifteen; 16: sixteen;
+            17: seventeen; 18: eighteen; 19: nineteen; 20: twenty[-→→];
+         %year:
+            0: =%%digits=;
+            1100>: ←%%digits← hundred[ →%%digits→];",
+    )
+    .unwrap();
+    assert_eq!(1_200_u32.to_nominal(&years), "twelve hundred");
+    assert_eq!(1_912_u32.to_nominal(&years), "nineteen hundred twelve");
+    assert_eq!(2_024_u32.to_nominal(&years), "twenty hundred twenty-four");
+}
+
+#[test]
+fn rule_errors() {
+    let error = |rules: &str| RuleBasedSystem::parse(rules).unwrap_err();
+
+    assert_eq!(error("").kind, RuleErrorKind::Empty);
+    assert_eq!(error("%%private: 0: zero;").kind, RuleErrorKind::Empty);
+    assert_eq!(
+        error("0: zero; %a 1: one;"),
+        RuleError {
+            offset: 9,
+            kind: RuleErrorKind::InvalidRuleSet
+        }
+    );
+    assert_eq!(
+        error("%a: 0: zero; %a: 0: zero;").kind,
+        RuleErrorKind::DuplicateRuleSet
+    );
+    assert_eq!(error("1/1: one;").kind, RuleErrorKind::InvalidDescriptor);
+    assert_eq!(error("1: one; 1: one;").kind, RuleErrorKind::OutOfOrder);
+    assert_eq!(
+        error("0: zero; 10: ←%%teen tens;").kind,
+        RuleErrorKind::UnterminatedSubstitution
+    );
+    assert_eq!(
+        error("0: zero; 10: ←x← tens;").kind,
+        RuleErrorKind::InvalidSubstitution
+    );
+    assert_eq!(error("0: →→→;").kind, RuleErrorKind::InvalidSubstitution);
+    assert_eq!(
+        error("0: zero; 10: ←← tens[ →→;").kind,
+        RuleErrorKind::UnbalancedBrackets
+    );
+    assert_eq!(
+        error("0: zero; 10: ←%missing← tens;"),
+        RuleError {
+            offset: 16,
+            kind: RuleErrorKind::UnknownRuleSet
+        }
+    );
+}
+
+#[test]
+fn rule_limits() {
+    use crate::Nominal;
+
+    let rules = RuleBasedSystem::parse("%a: 5: five; 6: ==;").unwrap();
+    assert_eq!(5_u8.to_nominal(&rules), "five");
+    assert_eq!(4_u8.try_to_nominal(&rules), Err(Error::OutOfBounds(4)));
+    assert_eq!(6_u8.try_to_nominal(&rules), Err(Error::OutOfBounds(6)));
+
+    let rules = RuleBasedSystem::parse("%a: 0: zero; 1,000,000: ←← million;").unwrap();
+    assert_eq!(u8::MAX.to_nominal(&rules), "zero");
+}
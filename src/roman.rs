@@ -0,0 +1,1080 @@
+use crate::succession::step_parsed;
+use crate::{
+    Error, NominalString, NominalSuccession, NominalSystem, OutOfMemoryError, SuccessionError,
+    UnsignedInteger, WithNominal,
+};
+
+/// Roman numerals with configurable notation.
+///
+/// [`RomanUpper`] and [`RomanLower`] are presets of this type. Starting from
+/// [`Roman::upper()`] or [`Roman::lower()`], variations can be selected using
+/// the builder-style functions:
+///
+/// ```rust
+/// use nominals::{Error, Nominal, Roman};
+///
+/// assert_eq!(4_u32.to_nominal(&Roman::upper()), "IV");
+/// assert_eq!(4_u32.to_nominal(&Roman::upper().clock_face()), "IIII");
+/// assert_eq!(9_u32.to_nominal(&Roman::upper().additive()), "VIIII");
+/// assert_eq!(8_u32.to_nominal(&Roman::lower().final_j()), "viij");
+/// assert_eq!(12_u32.to_nominal(&Roman::upper().number_forms()), "Ⅻ");
+/// assert_eq!(1_000_u32.to_nominal(&Roman::upper().apostrophus()), "CIↃ");
+/// assert_eq!(0_u32.to_nominal(&Roman::lower().zero("nulla")), "nulla");
+/// assert_eq!(
+///     0_u32.try_to_nominal(&Roman::upper().without_zero()),
+///     Err(Error::NoZeroSymbol)
+/// );
+/// ```
+///
+/// Except for [parenthetical](Self::parenthetical) notation, the largest
+/// symbol of each notation is repeated to write larger numbers. Nominals that
+/// would repeat the largest symbol more than 10,000 times are out of bounds:
+///
+/// ```rust
+/// use nominals::{Error, Nominal, Roman};
+///
+/// let repeated = Roman::upper().without_vinculum();
+/// assert_eq!(
+///     10_001_000_u32.try_to_nominal(&repeated),
+///     Err(Error::OutOfBounds(10_001_000))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Roman {
+    lowercase: bool,
+    subtraction: Subtraction,
+    large: LargeNotation,
+    vinculum: char,
+    number_forms: bool,
+    final_j: bool,
+    zero: Option<&'static str>,
+}
+
+impl Roman {
+    /// Returns uppercase Roman numerals using subtractive notation, vinculum
+    /// notation for numbers 4,000 and above, and `N` for zero.
+    ///
+    /// This produces identical results to [`RomanUpper`].
+    #[must_use]
+    pub const fn upper() -> Self {
+        Self {
+            lowercase: false,
+            subtraction: Subtraction::Subtractive,
+            large: LargeNotation::Vinculum,
+            vinculum: '\u{305}',
+            number_forms: false,
+            final_j: false,
+            zero: Some("N"),
+        }
+    }
+
+    /// Returns lowercase Roman numerals using subtractive notation, vinculum
+    /// notation for numbers 4,000 and above, and `n` for zero.
+    ///
+    /// This produces identical results to [`RomanLower`].
+    #[must_use]
+    pub const fn lower() -> Self {
+        Self {
+            lowercase: true,
+            zero: Some("n"),
+            ..Self::upper()
+        }
+    }
+
+    /// Returns a variation that writes 4 as `IIII`, as is traditionally done on
+    /// clock faces.
+    ///
+    /// All other subtractive forms, including 9 (`IX`) and 40 (`XL`), are
+    /// still used.
+    #[must_use]
+    pub const fn clock_face(mut self) -> Self {
+        self.subtraction = Subtraction::ClockFace;
+        self
+    }
+
+    /// Returns a variation that only uses additive notation. For example, 4 is
+    /// written `IIII` and 9 is written `VIIII`.
+    #[must_use]
+    pub const fn additive(mut self) -> Self {
+        self.subtraction = Subtraction::Additive;
+        self
+    }
+
+    /// Returns a variation that replaces a trailing `i` with `j`, as was
+    /// customary in medieval manuscripts. For example, 3 is written `iij`.
+    ///
+    /// This option has no effect when [`number_forms()`](Self::number_forms)
+    /// is enabled.
+    #[must_use]
+    pub const fn final_j(mut self) -> Self {
+        self.final_j = true;
+        self
+    }
+
+    /// Returns a variation that uses the characters from the Unicode Number
+    /// Forms block (`Ⅰ`, `Ⅴ`, `Ⅹ`, ...) instead of Latin letters.
+    ///
+    /// The numbers 1 through 12 are formatted using their single-character
+    /// forms (`Ⅰ` through `Ⅻ`) when the character matches the selected
+    /// notation. When combined with [`apostrophus()`](Self::apostrophus), the
+    /// single-character apostrophus forms (`ↀ`, `ↁ`, `ↂ`, `ↇ`, `ↈ`) are used.
+    #[must_use]
+    pub const fn number_forms(mut self) -> Self {
+        self.number_forms = true;
+        self
+    }
+
+    /// Returns a variation that uses apostrophus notation for thousands
+    /// instead of vinculum notation. For example, 1,000 is written `CIↃ` and
+    /// 5,000 is written `IↃↃ`.
+    ///
+    /// Because `C` preceding `CIↃ` would be ambiguous, hundreds are written
+    /// additively. For example, 900 is written `DCCCC`. Nominals of
+    /// 1,000,100,000 and above are out of bounds.
+    #[must_use]
+    pub const fn apostrophus(mut self) -> Self {
+        self.large = LargeNotation::Apostrophus;
+        self
+    }
+
+    /// Returns a variation that draws vinculum notation using a combining
+    /// macron (U+0304) instead of a combining overline (U+0305).
+    ///
+    /// Many fonts render consecutive combining overlines as disjoint dashes,
+    /// while a macron is more widely supported.
+    ///
+    /// ```rust
+    /// use nominals::{Nominal, Roman};
+    ///
+    /// assert_eq!(4_000_u32.to_nominal(&Roman::upper().macron()), "I\u{304}V\u{304}");
+    /// ```
+    #[must_use]
+    pub const fn macron(mut self) -> Self {
+        self.vinculum = '\u{304}';
+        self
+    }
+
+    /// Returns a variation that uses a double overline (U+033F) to multiply
+    /// symbols by 1,000,000, in addition to the single vinculum that
+    /// multiplies symbols by 1,000.
+    ///
+    /// ```rust
+    /// use nominals::{Nominal, Roman};
+    ///
+    /// assert_eq!(
+    ///     4_000_000_u32.to_nominal(&Roman::upper().double_vinculum()),
+    ///     "I\u{33F}V\u{33F}"
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn double_vinculum(mut self) -> Self {
+        self.large = LargeNotation::DoubleVinculum;
+        self
+    }
+
+    /// Returns a variation that uses box notation, a vinculum with sidebars, to
+    /// multiply symbols by 100,000, in addition to the single vinculum that
+    /// multiplies symbols by 1,000.
+    ///
+    /// ```rust
+    /// use nominals::{Nominal, Roman};
+    ///
+    /// assert_eq!(
+    ///     5_000_000_u32.to_nominal(&Roman::upper().boxed_vinculum()),
+    ///     "|L\u{305}|"
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn boxed_vinculum(mut self) -> Self {
+        self.large = LargeNotation::BoxedVinculum;
+        self
+    }
+
+    /// Returns a variation that wraps symbols in parentheses instead of
+    /// drawing a vinculum. Each pair of parentheses multiplies the enclosed
+    /// symbols by 1,000, allowing every value to be formatted without
+    /// repeating a symbol more than a few times.
+    ///
+    /// ```rust
+    /// use nominals::{Nominal, Roman};
+    ///
+    /// assert_eq!(4_000_u32.to_nominal(&Roman::upper().parenthetical()), "(IV)");
+    /// assert_eq!(
+    ///     5_004_000_u32.to_nominal(&Roman::upper().parenthetical()),
+    ///     "((V))(IV)"
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn parenthetical(mut self) -> Self {
+        self.large = LargeNotation::Parenthetical;
+        self
+    }
+
+    /// Returns a variation that does not use any notation for large numbers.
+    /// Thousands are written by repeating `M`, and nominals of 10,001,000 and
+    /// above are out of bounds.
+    #[must_use]
+    pub const fn without_vinculum(mut self) -> Self {
+        self.large = LargeNotation::Repeated;
+        self
+    }
+
+    /// Returns a variation that formats `0` as `zero`.
+    #[must_use]
+    pub const fn zero(mut self, zero: &'static str) -> Self {
+        self.zero = Some(zero);
+        self
+    }
+
+    /// Returns a variation that returns [`Error::NoZeroSymbol`] when asked to
+    /// format `0`.
+    #[must_use]
+    pub const fn without_zero(mut self) -> Self {
+        self.zero = None;
+        self
+    }
+
+    fn letter(&self, letter: Letter) -> char {
+        let index = letter as usize;
+        match (self.number_forms, self.lowercase) {
+            (false, false) => ['I', 'V', 'X', 'L', 'C', 'D', 'M'][index],
+            (false, true) => ['i', 'v', 'x', 'l', 'c', 'd', 'm'][index],
+            (true, false) => ['Ⅰ', 'Ⅴ', 'Ⅹ', 'Ⅼ', 'Ⅽ', 'Ⅾ', 'Ⅿ'][index],
+            (true, true) => ['ⅰ', 'ⅴ', 'ⅹ', 'ⅼ', 'ⅽ', 'ⅾ', 'ⅿ'][index],
+        }
+    }
+
+    fn precomposed<T>(&self, nominal: &T) -> Option<char>
+    where
+        T: UnsignedInteger,
+    {
+        if !self.number_forms || *nominal > T::from(12) {
+            return None;
+        }
+        let value = nominal.as_usize();
+        let matches_notation = match self.subtraction {
+            Subtraction::Subtractive => true,
+            Subtraction::ClockFace => value != 4,
+            Subtraction::Additive => value != 4 && value != 9,
+        };
+        if !matches_notation {
+            return None;
+        }
+
+        let first = if self.lowercase { 0x2170 } else { 0x2160 };
+        // The value is guaranteed to be between 1 and 12.
+        #[allow(clippy::cast_possible_truncation)]
+        char::from_u32(first + value as u32 - 1)
+    }
+
+    /// Returns an error if formatting `nominal` would repeat the largest
+    /// symbol of this variation's notation more than [`MAX_REPETITIONS`]
+    /// times.
+    fn check_repetitions<T>(&self, nominal: &T) -> Result<(), Error<T>>
+    where
+        T: UnsignedInteger + TryFrom<u128>,
+    {
+        let largest: u128 = match self.large {
+            LargeNotation::Vinculum => 1_000_000,
+            LargeNotation::DoubleVinculum => 1_000_000_000,
+            LargeNotation::BoxedVinculum => 100_000_000,
+            LargeNotation::Apostrophus => 100_000,
+            LargeNotation::Repeated => 1_000,
+            LargeNotation::Parenthetical => return Ok(()),
+        };
+        // When either value can't be represented by `T`, the limit can't be
+        // exceeded.
+        let (Ok(largest), Ok(max)) = (T::try_from(largest), T::try_from(MAX_REPETITIONS)) else {
+            return Ok(());
+        };
+        if nominal.clone() / largest > max {
+            Err(Error::OutOfBounds(nominal.clone()))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn push_apostrophus<T>(
+        &self,
+        formatted: &mut NominalString,
+        remaining: &mut T,
+        nominal: &T,
+    ) -> Result<(), Error<T>>
+    where
+        T: UnsignedInteger + TryFrom<u128>,
+    {
+        for (value, single, lower, upper) in APOSTROPHUS {
+            let Ok(value) = T::try_from(value) else {
+                continue;
+            };
+            while *remaining >= value {
+                *remaining -= value.clone();
+                if self.number_forms {
+                    formatted.try_push(single).with_nominal(nominal.clone())?;
+                } else if self.lowercase {
+                    formatted
+                        .try_push_str(lower)
+                        .with_nominal(nominal.clone())?;
+                } else {
+                    formatted
+                        .try_push_str(upper)
+                        .with_nominal(nominal.clone())?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn push_tier<T>(
+        &self,
+        formatted: &mut NominalString,
+        remaining: &mut T,
+        nominal: &T,
+        tier: Tier,
+    ) -> Result<(), Error<T>>
+    where
+        T: UnsignedInteger + TryFrom<u128>,
+    {
+        let mut opened = false;
+        for (value, first, second) in TERMS {
+            // The smallest terms of each tier above the first overlap with the
+            // largest terms of the tier below it.
+            if value < tier.smallest_term {
+                continue;
+            }
+            // Thousands are written using apostrophus notation, and hundreds
+            // are written additively to avoid ambiguity.
+            if tier.below_apostrophus && value >= 400 && (value == 1000 || second.is_some()) {
+                continue;
+            }
+            if second.is_some() && !self.subtraction.allows(value, tier.scale) {
+                continue;
+            }
+            let Some(value) = u128::from(value).checked_mul(tier.scale) else {
+                continue;
+            };
+            let Ok(value) = T::try_from(value) else {
+                continue;
+            };
+
+            while *remaining >= value {
+                if !opened {
+                    opened = true;
+                    tier.notation
+                        .push_open(formatted)
+                        .with_nominal(nominal.clone())?;
+                }
+                *remaining -= value.clone();
+                let is_final = remaining.is_zero();
+                if let Some(second) = second {
+                    self.push_letter(formatted, first, tier, false)
+                        .with_nominal(nominal.clone())?;
+                    self.push_letter(formatted, second, tier, is_final)
+                        .with_nominal(nominal.clone())?;
+                } else {
+                    self.push_letter(formatted, first, tier, is_final)
+                        .with_nominal(nominal.clone())?;
+                }
+            }
+        }
+
+        if opened {
+            tier.notation
+                .push_close(formatted)
+                .with_nominal(nominal.clone())?;
+        }
+
+        Ok(())
+    }
+
+    fn push_letter(
+        &self,
+        formatted: &mut NominalString,
+        letter: Letter,
+        tier: Tier,
+        is_final: bool,
+    ) -> Result<(), OutOfMemoryError> {
+        if is_final && self.final_j && !self.number_forms && letter == Letter::I {
+            formatted.try_push(if self.lowercase { 'j' } else { 'J' })?;
+        } else {
+            formatted.try_push(self.letter(letter))?;
+        }
+        match tier.notation {
+            TierNotation::Combining(mark) | TierNotation::Boxed(mark) => formatted.try_push(mark),
+            TierNotation::Plain | TierNotation::Parenthesized(_) => Ok(()),
+        }
+    }
+
+    fn push_parenthesized<T>(
+        &self,
+        formatted: &mut NominalString,
+        remaining: &mut T,
+        nominal: &T,
+    ) -> Result<(), Error<T>>
+    where
+        T: UnsignedInteger + TryFrom<u128>,
+    {
+        // Find the largest number of parentheses needed to avoid repeating the
+        // largest symbol more than three times.
+        let mut depth = 0;
+        let mut scale = 1_u128;
+        while let Some(next_scale) = scale.checked_mul(1_000) {
+            match next_scale.checked_mul(4).map(T::try_from) {
+                Some(Ok(smallest)) if &smallest <= nominal => {
+                    depth += 1;
+                    scale = next_scale;
+                }
+                _ => break,
+            }
+        }
+
+        while depth > 0 {
+            self.push_tier(
+                formatted,
+                remaining,
+                nominal,
+                Tier::large(scale, 4, TierNotation::Parenthesized(depth)),
+            )?;
+            depth -= 1;
+            scale /= 1_000;
+        }
+
+        Ok(())
+    }
+}
+
+impl Roman {
+    /// Returns the value of `nominal`, or `None` if it contains text that
+    /// isn't part of a Roman numeral.
+    ///
+    /// Every notation is accepted regardless of this variation's options, so
+    /// callers must verify the value formats back to `nominal`.
+    fn parse(&self, nominal: &str) -> Option<u128> {
+        if nominal.is_empty() {
+            return None;
+        } else if self.zero == Some(nominal) {
+            return Some(0);
+        }
+
+        let mut total = 0_u128;
+        let mut previous = 0_u128;
+        let mut depth = 0_u32;
+        let mut boxed = false;
+        let mut remaining = nominal;
+        while let Some(ch) = remaining.chars().next() {
+            match ch {
+                '(' => depth += 1,
+                ')' => depth = depth.checked_sub(1)?,
+                '|' => boxed = !boxed,
+                _ => {}
+            }
+            if matches!(ch, '(' | ')' | '|') {
+                remaining = &remaining[1..];
+                continue;
+            }
+
+            let (mut value, len, is_letter) = parse_symbol(remaining)?;
+            remaining = &remaining[len..];
+            if is_letter {
+                while let Some(mark) = remaining.chars().next() {
+                    let multiplier = match mark {
+                        '\u{305}' | '\u{304}' if boxed => 100_000,
+                        '\u{305}' | '\u{304}' => 1_000,
+                        '\u{33F}' => 1_000_000,
+                        _ => break,
+                    };
+                    value = value.checked_mul(multiplier)?;
+                    remaining = &remaining[mark.len_utf8()..];
+                }
+            }
+            value = value.checked_mul(1_000_u128.checked_pow(depth)?)?;
+
+            // A symbol followed by a larger symbol is subtracted from it.
+            total = total.checked_add(value)?;
+            if previous < value {
+                total = total.checked_sub(previous * 2)?;
+            }
+            previous = value;
+        }
+
+        (depth == 0 && !boxed).then_some(total)
+    }
+}
+
+/// Returns the value and length of the Roman numeral symbol at the start of
+/// `text`, and whether it is a letter that can be followed by combining marks.
+fn parse_symbol(text: &str) -> Option<(u128, usize, bool)> {
+    for (value, single, lower, upper) in APOSTROPHUS {
+        if let Some(symbol) = [lower, upper]
+            .into_iter()
+            .find(|symbol| text.starts_with(symbol))
+        {
+            return Some((value, symbol.len(), false));
+        } else if text.starts_with(single) {
+            return Some((value, single.len_utf8(), false));
+        }
+    }
+
+    let ch = text.chars().next()?;
+    let value = match ch.to_ascii_uppercase() {
+        'I' | 'J' => 1,
+        'V' => 5,
+        'X' => 10,
+        'L' => 50,
+        'C' => 100,
+        'D' => 500,
+        'M' => 1000,
+        // The Number Forms block contains Ⅰ through Ⅻ followed by Ⅼ, Ⅽ, Ⅾ,
+        // and Ⅿ, in both uppercase and lowercase.
+        _ => match u32::from(ch) {
+            code @ 0x2160..=0x217F => match (code - 0x2160) % 0x10 {
+                index @ 0..=11 => u128::from(index + 1),
+                12 => 50,
+                13 => 100,
+                14 => 500,
+                _ => 1000,
+            },
+            _ => return None,
+        },
+    };
+    Some((value, ch.len_utf8(), true))
+}
+
+impl Default for Roman {
+    fn default() -> Self {
+        Self::upper()
+    }
+}
+
+impl<T> NominalSystem<T> for Roman
+where
+    T: UnsignedInteger + TryFrom<u128>,
+{
+    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+        if nominal.is_zero() {
+            return if let Some(zero) = self.zero {
+                Ok(NominalString::from(zero))
+            } else {
+                Err(Error::NoZeroSymbol)
+            };
+        }
+
+        if let Some(precomposed) = self.precomposed(&nominal) {
+            return Ok(NominalString::from(precomposed));
+        }
+        self.check_repetitions(&nominal)?;
+
+        let mut formatted = NominalString::new();
+        let mut remaining = nominal.clone();
+        let mut ones = Tier::PLAIN;
+        let vinculum = Tier::large(1_000, 4, TierNotation::Combining(self.vinculum));
+        match self.large {
+            LargeNotation::Vinculum => {
+                self.push_tier(&mut formatted, &mut remaining, &nominal, vinculum)?;
+            }
+            LargeNotation::DoubleVinculum => {
+                self.push_tier(
+                    &mut formatted,
+                    &mut remaining,
+                    &nominal,
+                    Tier::large(1_000_000, 4, TierNotation::Combining('\u{33F}')),
+                )?;
+                self.push_tier(&mut formatted, &mut remaining, &nominal, vinculum)?;
+            }
+            LargeNotation::BoxedVinculum => {
+                self.push_tier(
+                    &mut formatted,
+                    &mut remaining,
+                    &nominal,
+                    Tier::large(100_000, 40, TierNotation::Boxed(self.vinculum)),
+                )?;
+                self.push_tier(&mut formatted, &mut remaining, &nominal, vinculum)?;
+            }
+            LargeNotation::Parenthetical => {
+                self.push_parenthesized(&mut formatted, &mut remaining, &nominal)?;
+            }
+            LargeNotation::Apostrophus => {
+                self.push_apostrophus(&mut formatted, &mut remaining, &nominal)?;
+                ones.below_apostrophus = true;
+            }
+            LargeNotation::Repeated => {}
+        }
+        self.push_tier(&mut formatted, &mut remaining, &nominal, ones)?;
+
+        Ok(formatted)
+    }
+}
+
+impl NominalSuccession for Roman {
+    fn next_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        step_parsed(
+            nominal,
+            true,
+            |nominal| self.parse(nominal),
+            |value| self.try_format_nominal(value),
+        )
+    }
+
+    fn prev_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        step_parsed(
+            nominal,
+            false,
+            |nominal| self.parse(nominal),
+            |value| self.try_format_nominal(value),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum Subtraction {
+    Subtractive,
+    ClockFace,
+    Additive,
+}
+
+impl Subtraction {
+    fn allows(self, value: u16, scale: u128) -> bool {
+        match self {
+            Subtraction::Subtractive => true,
+            Subtraction::ClockFace => value != 4 || scale > 1,
+            Subtraction::Additive => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum LargeNotation {
+    Vinculum,
+    DoubleVinculum,
+    BoxedVinculum,
+    Parenthetical,
+    Apostrophus,
+    Repeated,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Letter {
+    I,
+    V,
+    X,
+    L,
+    C,
+    D,
+    M,
+}
+
+/// A range of magnitudes that are written using the same notation.
+#[derive(Debug, Clone, Copy)]
+struct Tier {
+    scale: u128,
+    smallest_term: u16,
+    notation: TierNotation,
+    below_apostrophus: bool,
+}
+
+impl Tier {
+    const PLAIN: Self = Self::large(1, 1, TierNotation::Plain);
+
+    const fn large(scale: u128, smallest_term: u16, notation: TierNotation) -> Self {
+        Self {
+            scale,
+            smallest_term,
+            notation,
+            below_apostrophus: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum TierNotation {
+    Plain,
+    Combining(char),
+    Boxed(char),
+    Parenthesized(usize),
+}
+
+impl TierNotation {
+    fn push_open(self, formatted: &mut NominalString) -> Result<(), OutOfMemoryError> {
+        match self {
+            TierNotation::Boxed(_) => formatted.try_push('|'),
+            TierNotation::Parenthesized(depth) => {
+                for _ in 0..depth {
+                    formatted.try_push('(')?;
+                }
+                Ok(())
+            }
+            TierNotation::Plain | TierNotation::Combining(_) => Ok(()),
+        }
+    }
+
+    fn push_close(self, formatted: &mut NominalString) -> Result<(), OutOfMemoryError> {
+        match self {
+            TierNotation::Boxed(_) => formatted.try_push('|'),
+            TierNotation::Parenthesized(depth) => {
+                for _ in 0..depth {
+                    formatted.try_push(')')?;
+                }
+                Ok(())
+            }
+            TierNotation::Plain | TierNotation::Combining(_) => Ok(()),
+        }
+    }
+}
+
+const TERMS: [(u16, Letter, Option<Letter>); 13] = [
+    (1000, Letter::M, None),
+    (900, Letter::C, Some(Letter::M)),
+    (500, Letter::D, None),
+    (400, Letter::C, Some(Letter::D)),
+    (100, Letter::C, None),
+    (90, Letter::X, Some(Letter::C)),
+    (50, Letter::L, None),
+    (40, Letter::X, Some(Letter::L)),
+    (10, Letter::X, None),
+    (9, Letter::I, Some(Letter::X)),
+    (5, Letter::V, None),
+    (4, Letter::I, Some(Letter::V)),
+    (1, Letter::I, None),
+];
+
+/// The maximum number of times the largest symbol of a notation is repeated.
+const MAX_REPETITIONS: u128 = 10_000;
+
+const APOSTROPHUS: [(u128, char, &str, &str); 5] = [
+    (100_000, 'ↈ', "ccciↄↄↄ", "CCCIↃↃↃ"),
+    (50_000, 'ↇ', "iↄↄↄ", "IↃↃↃ"),
+    (10_000, 'ↂ', "cciↄↄ", "CCIↃↃ"),
+    (5_000, 'ↁ', "iↄↄ", "IↃↃ"),
+    (1_000, 'ↀ', "ciↄ", "CIↃ"),
+];
+
+/// Lowercase Roman numerals.
+///
+/// This encoding utilizes Vinculum notation for numbers 4,000 and above. This
+/// notation uses an overline over a repeated set of symbols. A few examples:
+///
+/// ```rust
+/// use nominals::{Nominal, RomanLower};
+///
+/// assert_eq!(4_000_u32.to_nominal(&RomanLower), "i̅v̅");
+/// assert_eq!(4_001_u32.to_nominal(&RomanLower), "i̅v̅i");
+/// assert_eq!(2_000_000_u32.to_nominal(&RomanLower), "m̅m̅");
+/// ```
+///
+/// Other variations of Roman numerals can be formatted using [`Roman`].
+#[doc = include_str!("./previews/RomanLower.md")]
+pub struct RomanLower;
+
+impl<T> NominalSystem<T> for RomanLower
+where
+    T: UnsignedInteger + TryFrom<u128>,
+{
+    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+        Roman::lower().try_format_nominal(nominal)
+    }
+}
+
+impl NominalSuccession for RomanLower {
+    fn next_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        Roman::lower().next_nominal(nominal)
+    }
+
+    fn prev_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        Roman::lower().prev_nominal(nominal)
+    }
+}
+
+/// Uppercase Roman numerals
+///
+/// This encoding utilizes Vinculum notation for numbers 4,000 and above. This
+/// notation uses an overline over a repeated set of symbols. A few examples:
+///
+/// ```rust
+/// use nominals::{Nominal, RomanUpper};
+///
+/// assert_eq!(4000_u32.to_nominal(&RomanUpper), "I̅V̅");
+/// assert_eq!(4_001_u32.to_nominal(&RomanUpper), "I̅V̅I");
+/// assert_eq!(2_000_000_u32.to_nominal(&RomanUpper), "M̅M̅");
+/// ```
+///
+/// Other variations of Roman numerals can be formatted using [`Roman`].
+#[doc = include_str!("./previews/RomanUpper.md")]
+pub struct RomanUpper;
+
+impl<T> NominalSystem<T> for RomanUpper
+where
+    T: UnsignedInteger + TryFrom<u128>,
+{
+    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+        Roman::upper().try_format_nominal(nominal)
+    }
+}
+
+impl NominalSuccession for RomanUpper {
+    fn next_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        Roman::upper().next_nominal(nominal)
+    }
+
+    fn prev_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError> {
+        Roman::upper().prev_nominal(nominal)
+    }
+}
+
+#[test]
+fn roman() {
+    use crate::Nominal;
+
+    assert_eq!(0_u32.to_nominal(&RomanUpper), "N");
+    assert_eq!(1_u32.to_nominal(&RomanUpper), "I");
+    assert_eq!(2_u32.to_nominal(&RomanUpper), "II");
+    assert_eq!(3_u32.to_nominal(&RomanUpper), "III");
+    assert_eq!(4_u32.to_nominal(&RomanUpper), "IV");
+    assert_eq!(5_u32.to_nominal(&RomanUpper), "V");
+    assert_eq!(0_u32.to_nominal(&RomanLower), "n");
+    assert_eq!(1_u32.to_nominal(&RomanLower), "i");
+    assert_eq!(2_u32.to_nominal(&RomanLower), "ii");
+    assert_eq!(3_u32.to_nominal(&RomanLower), "iii");
+    assert_eq!(4_u32.to_nominal(&RomanLower), "iv");
+    assert_eq!(5_u32.to_nominal(&RomanLower), "v");
+    assert_eq!(4000_u32.to_nominal(&RomanLower), "i̅v̅");
+    assert_eq!(2_000_000_u32.to_nominal(&RomanLower), "m̅m̅");
+    assert_eq!(999_999_u32.to_nominal(&RomanUpper), "C̅M̅X̅C̅I̅X̅CMXCIX");
+    assert_eq!(3_999_u32.to_nominal(&RomanUpper), "MMMCMXCIX");
+    assert_eq!(8_000_u32.to_nominal(&RomanUpper), "V̅MMM");
+}
+
+#[test]
+fn long_test() {
+    use crate::Nominal;
+
+    assert_eq!(
+        63_000_000_u32.to_nominal(&RomanLower),
+        "m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅m̅"
+    );
+}
+
+#[test]
+fn roman_variants() {
+    use crate::Nominal;
+
+    let clock = Roman::upper().clock_face();
+    assert_eq!(4_u32.to_nominal(&clock), "IIII");
+    assert_eq!(9_u32.to_nominal(&clock), "IX");
+    assert_eq!(14_u32.to_nominal(&clock), "XIIII");
+    assert_eq!(40_u32.to_nominal(&clock), "XL");
+
+    let additive = Roman::upper().additive();
+    assert_eq!(4_u32.to_nominal(&additive), "IIII");
+    assert_eq!(9_u32.to_nominal(&additive), "VIIII");
+    assert_eq!(1999_u32.to_nominal(&additive), "MDCCCCLXXXXVIIII");
+    assert_eq!(4_000_u32.to_nominal(&additive), "MMMM");
+
+    let medieval = Roman::lower().final_j();
+    assert_eq!(1_u32.to_nominal(&medieval), "j");
+    assert_eq!(3_u32.to_nominal(&medieval), "iij");
+    assert_eq!(4_u32.to_nominal(&medieval), "iv");
+    assert_eq!(8_u32.to_nominal(&medieval), "viij");
+    assert_eq!(2_000_u32.to_nominal(&Roman::upper().final_j()), "MM");
+
+    let forms = Roman::upper().number_forms();
+    assert_eq!(1_u32.to_nominal(&forms), "Ⅰ");
+    assert_eq!(4_u32.to_nominal(&forms), "Ⅳ");
+    assert_eq!(12_u32.to_nominal(&forms), "Ⅻ");
+    assert_eq!(13_u32.to_nominal(&forms), "ⅩⅠⅠⅠ");
+    assert_eq!(4_u32.to_nominal(&forms.clock_face()), "ⅠⅠⅠⅠ");
+    assert_eq!(7_u32.to_nominal(&Roman::lower().number_forms()), "ⅶ");
+
+    let apostrophus = Roman::upper().apostrophus();
+    assert_eq!(900_u32.to_nominal(&apostrophus), "DCCCC");
+    assert_eq!(400_u32.to_nominal(&apostrophus), "CCCC");
+    assert_eq!(1_999_u32.to_nominal(&apostrophus), "CIↃDCCCCXCIX");
+    assert_eq!(16_000_u32.to_nominal(&apostrophus), "CCIↃↃIↃↃCIↃ");
+    assert_eq!(
+        Roman::lower().apostrophus().format_nominal(11_000_u32),
+        "cciↄↄciↄ"
+    );
+    assert_eq!(
+        Roman::upper()
+            .apostrophus()
+            .number_forms()
+            .format_nominal(166_000_u32),
+        "ↈↇↂↁↀ"
+    );
+
+    assert_eq!(
+        4_000_u32.to_nominal(&Roman::upper().without_vinculum()),
+        "MMMM"
+    );
+    assert_eq!(0_u32.to_nominal(&Roman::upper().zero("nulla")), "nulla");
+    assert_eq!(
+        Roman::upper().without_zero().try_format_nominal(0_u32),
+        Err(Error::NoZeroSymbol)
+    );
+}
+
+#[test]
+fn roman_large_notation() {
+    use crate::Nominal;
+
+    assert_eq!(
+        4_001_u32.to_nominal(&Roman::lower().macron()),
+        "i\u{304}v\u{304}i"
+    );
+
+    let double = Roman::upper().double_vinculum();
+    assert_eq!(3_999_999_u32.to_nominal(&double), "M̅M̅M̅C̅M̅X̅C̅I̅X̅CMXCIX");
+    assert_eq!(4_000_000_u32.to_nominal(&double), "I\u{33F}V\u{33F}");
+    assert_eq!(2_000_000_000_u32.to_nominal(&double), "M\u{33F}M\u{33F}");
+
+    let boxed = Roman::upper().boxed_vinculum();
+    assert_eq!(999_999_u32.to_nominal(&boxed), "C̅M̅X̅C̅I̅X̅CMXCIX");
+    assert_eq!(3_000_000_u32.to_nominal(&boxed), "M̅M̅M̅");
+    assert_eq!(4_500_000_u32.to_nominal(&boxed), "|X\u{305}L\u{305}|D̅");
+    assert_eq!(
+        300_000_000_u32.to_nominal(&boxed),
+        "|M\u{305}M\u{305}M\u{305}|"
+    );
+
+    let parens = Roman::upper().parenthetical();
+    assert_eq!(3_999_u32.to_nominal(&parens), "MMMCMXCIX");
+    assert_eq!(4_001_u32.to_nominal(&parens), "(IV)I");
+    assert_eq!(999_999_u32.to_nominal(&parens), "(CMXCIX)CMXCIX");
+    assert_eq!(1_000_000_u32.to_nominal(&parens), "(M)");
+    assert_eq!(
+        u64::MAX.to_nominal(&parens),
+        "((((((XV))))))(((((MMMCDXLV)))))((((MDCCXLIV))))(((LXX)))((MMMDCCIX))(DL)MDCXV"
+    );
+    assert!(u128::MAX
+        .to_nominal(&Roman::lower().parenthetical())
+        .starts_with("((((((((((((cccxl))))))))))))(((((((((((cclxxx)))))))))))"));
+}
+
+#[test]
+fn repetition_limit() {
+    use crate::Nominal;
+
+    let repeated = Roman::upper().without_vinculum();
+    assert_eq!(10_000_999_u32.to_nominal(&repeated).len(), 10_006);
+    assert_eq!(
+        10_001_000_u32.try_to_nominal(&repeated),
+        Err(Error::OutOfBounds(10_001_000))
+    );
+    assert_eq!(
+        u64::MAX.try_to_nominal(&repeated),
+        Err(Error::OutOfBounds(u64::MAX))
+    );
+
+    let apostrophus = Roman::upper().apostrophus();
+    assert!(1_000_099_999_u32
+        .to_nominal(&apostrophus.number_forms())
+        .starts_with("ↈↈↈ"));
+    assert_eq!(
+        1_000_100_000_u32.try_to_nominal(&apostrophus),
+        Err(Error::OutOfBounds(1_000_100_000))
+    );
+    assert_eq!(
+        u64::MAX.try_to_nominal(&apostrophus),
+        Err(Error::OutOfBounds(u64::MAX))
+    );
+
+    for system in [
+        Roman::upper(),
+        Roman::upper().double_vinculum(),
+        Roman::upper().boxed_vinculum(),
+    ] {
+        assert_eq!(
+            u128::MAX.try_to_nominal(&system),
+            Err(Error::OutOfBounds(u128::MAX))
+        );
+    }
+    assert!(u128::MAX
+        .try_to_nominal(&Roman::upper().parenthetical())
+        .is_ok());
+}
+
+#[test]
+fn succession() {
+    for system in [
+        Roman::upper(),
+        Roman::lower(),
+        Roman::upper().clock_face(),
+        Roman::lower().additive(),
+        Roman::lower().final_j(),
+        Roman::upper().number_forms(),
+        Roman::upper().apostrophus(),
+        Roman::upper().macron(),
+        Roman::upper().without_vinculum(),
+        Roman::upper().without_zero(),
+    ] {
+        for n in 0_u32..10_000 {
+            let Ok(formatted) = system.try_format_nominal(n) else {
+                continue;
+            };
+            let next = system.format_nominal(n + 1);
+            assert_eq!(
+                system.next_nominal(&formatted).unwrap(),
+                next,
+                "{system:?} {n}"
+            );
+            assert_eq!(
+                system.prev_nominal(&next).unwrap(),
+                formatted,
+                "{system:?} {n}"
+            );
+        }
+    }
+    for system in [
+        Roman::upper().double_vinculum(),
+        Roman::upper().boxed_vinculum(),
+        Roman::upper().parenthetical(),
+    ] {
+        for n in [
+            3_999_999_u32,
+            4_499_999,
+            4_500_000,
+            299_999_999,
+            1_000_000_000,
+        ] {
+            let formatted = system.format_nominal(n);
+            let next = system.format_nominal(n + 1);
+            assert_eq!(
+                system.next_nominal(&formatted).unwrap(),
+                next,
+                "{system:?} {n}"
+            );
+            assert_eq!(
+                system.prev_nominal(&next).unwrap(),
+                formatted,
+                "{system:?} {n}"
+            );
+        }
+    }
+
+    assert_eq!(RomanLower.next_nominal("xiv").unwrap(), "xv");
+    assert_eq!(RomanUpper.prev_nominal("I̅V̅").unwrap(), "MMMCMXCIX");
+    assert_eq!(RomanUpper.prev_nominal("I").unwrap(), "N");
+    assert_eq!(
+        RomanUpper.prev_nominal("N"),
+        Err(SuccessionError::NoPredecessor)
+    );
+    assert_eq!(
+        Roman::upper().without_zero().prev_nominal("I"),
+        Err(SuccessionError::NoPredecessor)
+    );
+    assert_eq!(
+        RomanLower.next_nominal("XIV"),
+        Err(SuccessionError::InvalidNominal)
+    );
+    assert_eq!(
+        RomanUpper.next_nominal("IIII"),
+        Err(SuccessionError::InvalidNominal)
+    );
+    assert_eq!(
+        Roman::upper().clock_face().next_nominal("IIII").unwrap(),
+        "V"
+    );
+    assert_eq!(
+        RomanUpper.next_nominal("(IV)"),
+        Err(SuccessionError::InvalidNominal)
+    );
+}
@@ -0,0 +1,738 @@
+use crate::{Error, NominalString, NominalSystem, OutOfMemoryError, UnsignedInteger, WithNominal};
+
+/// The Hanyu Pinyin reading of Chinese numerals, such as `yìbǎi èrshí`.
+///
+/// Each nominal is formatted using the wrapped system, and each character is
+/// replaced with its reading. The tone of 一 and 不 changes based on the
+/// following syllable: 一 is read `yì` before 百 and 千 but `yí` before 万 and
+/// 亿, and keeps its original tone before 十 and at the end of a number.
+/// Third tone sandhi is not written, following the Pinyin orthography rules.
+///
+/// Characters that have no reading, such as ASCII digits, are written as-is.
+///
+/// ```rust
+/// use nominals::{Nominal, Pinyin, SimplifiedChineseInformal};
+///
+/// let pinyin = Pinyin::new(SimplifiedChineseInformal::default());
+/// assert_eq!(112_u32.to_nominal(&pinyin), "yìbǎiyīshí'èr");
+/// assert_eq!(10_000_u32.to_nominal(&pinyin), "yíwàn");
+/// assert_eq!(21_u32.to_nominal(&pinyin.tone_numbers()), "er4shi2yi1");
+/// assert_eq!(
+///     3_u32.to_nominal(&pinyin.ruby()),
+///     "<ruby>三<rt>sān</rt></ruby>"
+/// );
+/// ```
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Pinyin<S> {
+    system: S,
+    tone_numbers: bool,
+    ruby: bool,
+}
+
+impl<S> Pinyin<S> {
+    /// Returns the Pinyin reading of numbers formatted using `system`.
+    pub const fn new(system: S) -> Self {
+        Self {
+            system,
+            tone_numbers: false,
+            ruby: false,
+        }
+    }
+
+    /// Returns a variation that writes tones as numbers after each syllable,
+    /// such as `san1bai3` instead of `sānbǎi`.
+    #[must_use]
+    pub const fn tone_numbers(mut self) -> Self {
+        self.tone_numbers = true;
+        self
+    }
+
+    /// Returns a variation that writes HTML ruby markup pairing each character
+    /// with its reading, such as `<ruby>三<rt>sān</rt></ruby>`.
+    #[must_use]
+    pub const fn ruby(mut self) -> Self {
+        self.ruby = true;
+        self
+    }
+
+    /// Returns the system used to format numbers.
+    pub const fn system(&self) -> &S {
+        &self.system
+    }
+}
+
+impl<S> Romanizer for Pinyin<S> {
+    fn read(
+        &self,
+        _previous: Option<char>,
+        ch: char,
+        next: Option<char>,
+        reading: &mut NominalString,
+    ) -> Result<bool, OutOfMemoryError> {
+        let Some((syllable, mut tone)) = pinyin_syllable(ch) else {
+            return Ok(false);
+        };
+        let next = next.and_then(pinyin_syllable);
+        match (ch, next) {
+            // 一 changes tone before units other than 十.
+            ('一', Some((next_syllable, next_tone))) if next_syllable != "shi" => {
+                tone = if next_tone == 4 { 2 } else { 4 };
+            }
+            ('不', Some((_, 4))) => tone = 2,
+            _ => {}
+        }
+
+        if self.tone_numbers {
+            reading.try_push_str(syllable)?;
+            reading.try_push(char::from(b'0' + tone))?;
+        } else {
+            // The tone mark is placed on a or e, on the o of ou, or otherwise
+            // on the last vowel.
+            let marked = syllable
+                .find(['a', 'e'])
+                .or_else(|| syllable.find("ou"))
+                .or_else(|| syllable.rfind(['i', 'o', 'u']));
+            for (index, letter) in syllable.char_indices() {
+                if Some(index) == marked {
+                    reading.try_push(tone_mark(letter, tone))?;
+                } else {
+                    reading.try_push(letter)?;
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    fn apostrophe(&self, last: char, first: char) -> bool {
+        // Syllables starting with a, e, or o are separated from the previous
+        // syllable with an apostrophe: 十二 is shí'èr.
+        !self.tone_numbers && last.is_alphabetic() && "aāáǎàeēéěèoōóǒò".contains(first)
+    }
+}
+
+impl<S, T> NominalSystem<T> for Pinyin<S>
+where
+    S: NominalSystem<T>,
+    T: UnsignedInteger,
+{
+    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+        let formatted = self.system.try_format_nominal(nominal.clone())?;
+        romanize(self, &formatted, self.ruby).with_nominal(nominal)
+    }
+}
+
+/// Returns the Pinyin syllable and tone of `ch`.
+fn pinyin_syllable(ch: char) -> Option<(&'static str, u8)> {
+    Some(match ch {
+        '零' | '〇' => ("ling", 2),
+        '一' | '壹' => ("yi", 1),
+        '幺' => ("yao", 1),
+        '二' | '贰' | '貳' => ("er", 4),
+        '两' | '兩' => ("liang", 3),
+        '三' | '叁' | '參' => ("san", 1),
+        '四' | '肆' => ("si", 4),
+        '五' | '伍' => ("wu", 3),
+        '六' | '陆' | '陸' => ("liu", 4),
+        '七' | '柒' => ("qi", 1),
+        '八' | '捌' => ("ba", 1),
+        '九' | '玖' => ("jiu", 3),
+        '十' | '拾' => ("shi", 2),
+        '廿' => ("nian", 4),
+        '卅' => ("sa", 4),
+        '卌' => ("xi", 4),
+        '百' | '佰' => ("bai", 3),
+        '千' | '仟' => ("qian", 1),
+        '万' | '萬' => ("wan", 4),
+        '亿' | '億' | '议' | '議' => ("yi", 4),
+        '兆' => ("zhao", 4),
+        '京' => ("jing", 1),
+        '垓' => ("gai", 1),
+        '秭' => ("zi", 3),
+        '穰' => ("rang", 2),
+        '沟' | '溝' => ("gou", 1),
+        '涧' | '澗' => ("jian", 4),
+        '正' => ("zheng", 4),
+        '载' | '載' => ("zai", 4),
+        '极' | '極' => ("ji", 2),
+        '恒' | '恆' => ("heng", 2),
+        '河' => ("he", 2),
+        '沙' => ("sha", 1),
+        '阿' => ("a", 1),
+        '僧' => ("seng", 1),
+        '祇' => ("qi", 2),
+        '那' => ("na", 4),
+        '由' => ("you", 2),
+        '他' => ("ta", 1),
+        '不' => ("bu", 4),
+        '可' => ("ke", 3),
+        '思' => ("si", 1),
+        '无' | '無' => ("wu", 2),
+        '量' => ("liang", 4),
+        '大' => ("da", 4),
+        '数' | '數' => ("shu", 4),
+        '负' | '負' => ("fu", 4),
+        '第' => ("di", 4),
+        '点' | '點' => ("dian", 3),
+        '元' => ("yuan", 2),
+        '角' => ("jiao", 3),
+        '分' => ("fen", 1),
+        '整' => ("zheng", 3),
+        _ => return None,
+    })
+}
+
+/// Returns `vowel` with the tone mark for `tone`.
+fn tone_mark(vowel: char, tone: u8) -> char {
+    let marks = match vowel {
+        'a' => ['ā', 'á', 'ǎ', 'à'],
+        'e' => ['ē', 'é', 'ě', 'è'],
+        'i' => ['ī', 'í', 'ǐ', 'ì'],
+        'o' => ['ō', 'ó', 'ǒ', 'ò'],
+        'u' => ['ū', 'ú', 'ǔ', 'ù'],
+        _ => return vowel,
+    };
+    match tone {
+        1..=4 => marks[usize::from(tone - 1)],
+        _ => vowel,
+    }
+}
+
+/// The Hepburn romanization of Japanese numerals, such as `sanbyaku`.
+///
+/// Each nominal is formatted using the wrapped system, and each character is
+/// replaced with its reading. Readings change where Japanese pronunciation
+/// does: 三百 is `sanbyaku`, 六百 is `roppyaku`, 八千 is `hassen`, and 一兆
+/// is `itchō`. Long vowels are written with macrons.
+///
+/// Characters that have no reading, such as ASCII digits, are written as-is.
+///
+/// ```rust
+/// use nominals::{Hepburn, JapaneseInformal, Nominal};
+///
+/// let hepburn = Hepburn::new(JapaneseInformal);
+/// assert_eq!(300_u32.to_nominal(&hepburn), "sanbyaku");
+/// assert_eq!(800_u32.to_nominal(&hepburn), "happyaku");
+/// assert_eq!(
+///     600_u32.to_nominal(&hepburn.ruby()),
+///     "<ruby>六<rt>rop</rt>百<rt>pyaku</rt></ruby>"
+/// );
+/// ```
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Hepburn<S> {
+    system: S,
+    ruby: bool,
+}
+
+impl<S> Hepburn<S> {
+    /// Returns the Hepburn reading of numbers formatted using `system`.
+    pub const fn new(system: S) -> Self {
+        Self {
+            system,
+            ruby: false,
+        }
+    }
+
+    /// Returns a variation that writes HTML ruby markup pairing each character
+    /// with its reading, such as `<ruby>三<rt>san</rt></ruby>`.
+    #[must_use]
+    pub const fn ruby(mut self) -> Self {
+        self.ruby = true;
+        self
+    }
+
+    /// Returns the system used to format numbers.
+    pub const fn system(&self) -> &S {
+        &self.system
+    }
+}
+
+impl<S> Romanizer for Hepburn<S> {
+    fn read(
+        &self,
+        previous: Option<char>,
+        ch: char,
+        next: Option<char>,
+        reading: &mut NominalString,
+    ) -> Result<bool, OutOfMemoryError> {
+        let previous = previous.and_then(japanese_value);
+        // Before 京, the final vowel is dropped and the consonant doubled.
+        let before_kei = next == Some('京');
+        let next = match next {
+            Some('兆') => JAPANESE_CHO,
+            Some('京') => JAPANESE_KEI,
+            next => next.and_then(japanese_value).unwrap_or(0),
+        };
+        let syllables = match japanese_value(ch) {
+            Some(0) => "rei",
+            Some(1) => match next {
+                100 => "ip",
+                1000 => "is",
+                JAPANESE_CHO => "it",
+                JAPANESE_KEI => "ik",
+                _ => "ichi",
+            },
+            Some(2) => "ni",
+            Some(3) => "san",
+            Some(4) => "yon",
+            Some(5) => "go",
+            Some(6) => match next {
+                100 => "rop",
+                JAPANESE_KEI => "rok",
+                _ => "roku",
+            },
+            Some(7) => "nana",
+            Some(8) => match next {
+                100 => "hap",
+                1000 => "has",
+                JAPANESE_CHO => "hat",
+                JAPANESE_KEI => "hak",
+                _ => "hachi",
+            },
+            Some(9) => "kyū",
+            Some(10) => match next {
+                JAPANESE_CHO => "jut",
+                JAPANESE_KEI => "juk",
+                _ => "jū",
+            },
+            Some(100) => match (previous, before_kei) {
+                (Some(3), false) => "byaku",
+                (Some(3), true) => "byak",
+                (Some(1 | 6 | 8), false) => "pyaku",
+                (Some(1 | 6 | 8), true) => "pyak",
+                (_, false) => "hyaku",
+                (_, true) => "hyak",
+            },
+            Some(_) => {
+                if previous == Some(3) {
+                    "zen"
+                } else {
+                    "sen"
+                }
+            }
+            None => match ch {
+                '万' | '萬' => "man",
+                '億' => "oku",
+                '兆' => "chō",
+                '京' => "kei",
+                '垓' => "gai",
+                '𥝱' | '秭' => "jo",
+                '穣' => "jō",
+                '溝' => "kō",
+                '澗' => "kan",
+                '正' => "sei",
+                '載' => "sai",
+                '極' => "goku",
+                '恒' => "gō",
+                '河' => "ga",
+                '沙' => "sha",
+                '阿' => "a",
+                '僧' => "sō",
+                '祇' | '議' => "gi",
+                '那' => "na",
+                '由' => "yu",
+                '他' => "ta",
+                '不' => "fu",
+                '可' => "ka",
+                '思' => "shi",
+                '無' => "mu",
+                '量' => "ryō",
+                '大' => "tai",
+                '数' => "sū",
+                '第' => "dai",
+                '番' => "ban",
+                '目' => "me",
+                _ => return Ok(false),
+            },
+        };
+        reading.try_push_str(syllables)?;
+        Ok(true)
+    }
+
+    fn apostrophe(&self, last: char, first: char) -> bool {
+        // A syllabic n is separated from a following vowel or y with an
+        // apostrophe: 三億 is san'oku.
+        last == 'n' && "aeiouyāēīōū".contains(first)
+    }
+}
+
+impl<S, T> NominalSystem<T> for Hepburn<S>
+where
+    S: NominalSystem<T>,
+    T: UnsignedInteger,
+{
+    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+        let formatted = self.system.try_format_nominal(nominal.clone())?;
+        romanize(self, &formatted, self.ruby).with_nominal(nominal)
+    }
+}
+
+// Values used to look up readings that change before 兆 and 京.
+const JAPANESE_CHO: u32 = u32::MAX - 1;
+const JAPANESE_KEI: u32 = u32::MAX;
+
+/// Returns the value of a Japanese digit or 十, 百, or 千.
+fn japanese_value(ch: char) -> Option<u32> {
+    Some(match ch {
+        '〇' | '零' => 0,
+        '一' | '壱' => 1,
+        '二' | '弐' => 2,
+        '三' | '参' => 3,
+        '四' => 4,
+        '五' | '伍' => 5,
+        '六' => 6,
+        '七' => 7,
+        '八' => 8,
+        '九' => 9,
+        '十' | '拾' => 10,
+        '百' => 100,
+        '千' | '阡' => 1000,
+        _ => return None,
+    })
+}
+
+/// The Revised Romanization of Korean numerals, such as `baengman`.
+///
+/// Each nominal is formatted using the wrapped system, and each Hangeul
+/// syllable is replaced with its reading. As Revised Romanization follows
+/// pronunciation, the sound changes that occur in numbers are applied across
+/// syllables: 일억 is `ireok`, 백만 is `baengman`, and 십육 is `simnyuk`.
+///
+/// Characters other than Hangeul syllables, such as spaces, are written
+/// as-is.
+///
+/// ```rust
+/// use nominals::{HangeulFormal, Nominal, RevisedRomanization};
+///
+/// let korean = RevisedRomanization::new(HangeulFormal::default().without_leading_one());
+/// assert_eq!(16_u32.to_nominal(&korean), "simnyuk");
+/// assert_eq!(1_000_000_u32.to_nominal(&korean), "baengman");
+/// assert_eq!(
+///     21_u32.to_nominal(&korean.ruby()),
+///     "<ruby>이<rt>i</rt>십<rt>si</rt>일<rt>bil</rt></ruby>"
+/// );
+/// ```
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct RevisedRomanization<S> {
+    system: S,
+    ruby: bool,
+}
+
+impl<S> RevisedRomanization<S> {
+    /// Returns the Revised Romanization of numbers formatted using `system`.
+    pub const fn new(system: S) -> Self {
+        Self {
+            system,
+            ruby: false,
+        }
+    }
+
+    /// Returns a variation that writes HTML ruby markup pairing each syllable
+    /// with its reading, such as `<ruby>삼<rt>sam</rt></ruby>`.
+    #[must_use]
+    pub const fn ruby(mut self) -> Self {
+        self.ruby = true;
+        self
+    }
+
+    /// Returns the system used to format numbers.
+    pub const fn system(&self) -> &S {
+        &self.system
+    }
+}
+
+impl<S> Romanizer for RevisedRomanization<S> {
+    fn read(
+        &self,
+        previous: Option<char>,
+        ch: char,
+        next: Option<char>,
+        reading: &mut NominalString,
+    ) -> Result<bool, OutOfMemoryError> {
+        let Some(syllable) = Syllable::new(ch) else {
+            return Ok(false);
+        };
+        let initial = match previous.and_then(Syllable::new) {
+            Some(previous) => previous.boundary(syllable).1,
+            None => HANGEUL_INITIALS[syllable.initial],
+        };
+        let final_consonant = match next.and_then(Syllable::new) {
+            Some(next) => syllable.boundary(next).0,
+            None => HANGEUL_FINALS[syllable.final_consonant],
+        };
+        reading.try_push_str(initial)?;
+        reading.try_push_str(HANGEUL_VOWELS[syllable.vowel])?;
+        reading.try_push_str(final_consonant)?;
+        Ok(true)
+    }
+}
+
+impl<S, T> NominalSystem<T> for RevisedRomanization<S>
+where
+    S: NominalSystem<T>,
+    T: UnsignedInteger,
+{
+    fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+        let formatted = self.system.try_format_nominal(nominal.clone())?;
+        romanize(self, &formatted, self.ruby).with_nominal(nominal)
+    }
+}
+
+const HANGEUL_INITIALS: [&str; 19] = [
+    "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t", "p",
+    "h",
+];
+const HANGEUL_VOWELS: [&str; 21] = [
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we",
+    "wi", "yu", "eu", "ui", "i",
+];
+// The romanization of each final consonant before another consonant or at the
+// end of a word.
+const HANGEUL_FINALS: [&str; 28] = [
+    "", "k", "k", "k", "n", "n", "n", "t", "l", "k", "m", "l", "l", "l", "p", "l", "m", "p", "p",
+    "t", "t", "ng", "t", "t", "k", "t", "p", "t",
+];
+
+// Indices of the jamo that sound changes depend on.
+const INITIAL_N: usize = 2;
+const INITIAL_R: usize = 5;
+const INITIAL_M: usize = 6;
+const INITIAL_SILENT: usize = 11;
+const INITIAL_H: usize = 18;
+const FINAL_NG: usize = 21;
+
+#[derive(Clone, Copy)]
+struct Syllable {
+    initial: usize,
+    vowel: usize,
+    final_consonant: usize,
+}
+
+impl Syllable {
+    fn new(ch: char) -> Option<Self> {
+        let index = u32::from(ch).checked_sub(0xAC00)?;
+        if index >= 11_172 {
+            return None;
+        }
+        let index = usize::try_from(index).ok()?;
+        Some(Self {
+            initial: index / 588,
+            vowel: index % 588 / 28,
+            final_consonant: index % 28,
+        })
+    }
+
+    /// Returns the initial consonant that a simple final consonant becomes
+    /// when it is carried over to a following vowel.
+    fn liaison(self) -> Option<&'static str> {
+        let initial = match self.final_consonant {
+            1 => 0,
+            2 => 1,
+            4 => 2,
+            7 => 3,
+            8 => INITIAL_R,
+            16 => INITIAL_M,
+            17 => 7,
+            19 => 9,
+            20 => 10,
+            22 => 12,
+            23 => 14,
+            24 => 15,
+            25 => 16,
+            26 => 17,
+            27 => INITIAL_SILENT,
+            _ => return None,
+        };
+        Some(HANGEUL_INITIALS[initial])
+    }
+
+    /// Returns the pronounced final consonant of `self` and initial consonant
+    /// of `next` when `next` directly follows `self`.
+    fn boundary(self, next: Self) -> (&'static str, &'static str) {
+        let final_consonant = HANGEUL_FINALS[self.final_consonant];
+        let initial = HANGEUL_INITIALS[next.initial];
+        if self.final_consonant == 0 {
+            return ("", initial);
+        }
+
+        // 육 (originally 륙) gains an initial ㄴ after a consonant, which then
+        // causes the same changes as any other ㄴ: 십육 is 심뉵.
+        let is_yuk =
+            next.initial == INITIAL_SILENT && next.vowel == 17 && next.final_consonant == 1;
+        let next_initial = if is_yuk { INITIAL_N } else { next.initial };
+        match (final_consonant, next_initial) {
+            (_, INITIAL_SILENT) if self.final_consonant == FINAL_NG => ("ng", ""),
+            (_, INITIAL_SILENT) => match self.liaison() {
+                Some(carried) => ("", carried),
+                None => (final_consonant, ""),
+            },
+            ("l", INITIAL_N | INITIAL_R) | ("n", INITIAL_R) => ("l", "l"),
+            ("k", INITIAL_N | INITIAL_M | INITIAL_R) => ("ng", nasal(next_initial)),
+            ("p", INITIAL_N | INITIAL_M | INITIAL_R) => ("m", nasal(next_initial)),
+            ("t", INITIAL_N | INITIAL_M | INITIAL_R) => ("n", nasal(next_initial)),
+            ("m" | "ng", INITIAL_R) => (final_consonant, "n"),
+            ("k", INITIAL_H) => ("", "k"),
+            ("p", INITIAL_H) => ("", "p"),
+            ("t", INITIAL_H) => ("", "t"),
+            _ => (final_consonant, HANGEUL_INITIALS[next_initial]),
+        }
+    }
+}
+
+/// Returns the romanization of a nasal initial consonant, where ㄹ after a
+/// nasalized final consonant is pronounced ㄴ.
+fn nasal(initial: usize) -> &'static str {
+    if initial == INITIAL_M {
+        "m"
+    } else {
+        "n"
+    }
+}
+
+trait Romanizer {
+    /// Pushes the reading of `ch` to `reading`, returning false if `ch` has no
+    /// reading.
+    fn read(
+        &self,
+        previous: Option<char>,
+        ch: char,
+        next: Option<char>,
+        reading: &mut NominalString,
+    ) -> Result<bool, OutOfMemoryError>;
+
+    /// Returns true if an apostrophe separates a reading ending with `last`
+    /// from a reading starting with `first`.
+    fn apostrophe(&self, _last: char, _first: char) -> bool {
+        false
+    }
+}
+
+fn romanize<R>(reader: &R, formatted: &str, ruby: bool) -> Result<NominalString, OutOfMemoryError>
+where
+    R: Romanizer,
+{
+    let mut romanized = NominalString::new();
+    if ruby {
+        romanized.try_push_str("<ruby>")?;
+    }
+
+    let mut previous = None;
+    let mut chars = formatted.chars().peekable();
+    while let Some(ch) = chars.next() {
+        let mut reading = NominalString::new();
+        let known = reader.read(previous, ch, chars.peek().copied(), &mut reading)?;
+        if ruby {
+            romanized.try_push(ch)?;
+            romanized.try_push_str("<rt>")?;
+            romanized.try_push_str(&reading)?;
+            romanized.try_push_str("</rt>")?;
+        } else if known {
+            if let (Some(last), Some(first)) =
+                (romanized.chars().next_back(), reading.chars().next())
+            {
+                if reader.apostrophe(last, first) {
+                    romanized.try_push('\'')?;
+                }
+            }
+            romanized.try_push_str(&reading)?;
+        } else {
+            romanized.try_push(ch)?;
+        }
+        previous = Some(ch);
+    }
+
+    if ruby {
+        romanized.try_push_str("</ruby>")?;
+    }
+    Ok(romanized)
+}
+
+#[test]
+fn pinyin() {
+    use crate::{Nominal, SimplifiedChineseFormal, SimplifiedChineseInformal};
+
+    let pinyin = Pinyin::new(SimplifiedChineseInformal::default());
+    assert_eq!(1_u32.to_nominal(&pinyin), "yī");
+    assert_eq!(12_u32.to_nominal(&pinyin), "shí'èr");
+    assert_eq!(100_u32.to_nominal(&pinyin), "yìbǎi");
+    assert_eq!(101_u32.to_nominal(&pinyin), "yìbǎilíngyī");
+    assert_eq!(111_u32.to_nominal(&pinyin), "yìbǎiyīshíyī");
+    assert_eq!(1_000_u32.to_nominal(&pinyin), "yìqiān");
+    assert_eq!(10_000_u32.to_nominal(&pinyin), "yíwàn");
+    assert_eq!(100_000_000_u32.to_nominal(&pinyin), "yíyì");
+    assert_eq!(
+        20_000_u32.to_nominal(&Pinyin::new(SimplifiedChineseInformal::default().liang())),
+        "liǎngwàn"
+    );
+    assert_eq!(
+        123_u32.to_nominal(&Pinyin::new(SimplifiedChineseFormal::default())),
+        "yìbǎi'èrshísān"
+    );
+    assert_eq!(21_u32.to_nominal(&pinyin.tone_numbers()), "er4shi2yi1");
+    assert_eq!(romanize(&pinyin, "不可思议", false).unwrap(), "bùkěsīyì");
+    assert_eq!(
+        12_u32.to_nominal(&pinyin.ruby()),
+        "<ruby>十<rt>shí</rt>二<rt>èr</rt></ruby>"
+    );
+}
+
+#[test]
+fn hepburn() {
+    use crate::{JapaneseFormal, JapaneseInformal, Nominal};
+
+    let hepburn = Hepburn::new(JapaneseInformal);
+    assert_eq!(0_u32.to_nominal(&hepburn), "rei");
+    assert_eq!(4_u32.to_nominal(&hepburn), "yon");
+    assert_eq!(19_u32.to_nominal(&hepburn), "jūkyū");
+    assert_eq!(100_u32.to_nominal(&hepburn), "hyaku");
+    assert_eq!(300_u32.to_nominal(&hepburn), "sanbyaku");
+    assert_eq!(600_u32.to_nominal(&hepburn), "roppyaku");
+    assert_eq!(800_u32.to_nominal(&hepburn), "happyaku");
+    assert_eq!(3_000_u32.to_nominal(&hepburn), "sanzen");
+    assert_eq!(8_000_u32.to_nominal(&hepburn), "hassen");
+    assert_eq!(10_000_u32.to_nominal(&hepburn), "ichiman");
+    assert_eq!(300_000_000_u32.to_nominal(&hepburn), "san'oku");
+    assert_eq!(1_000_000_000_000_u64.to_nominal(&hepburn), "itchō");
+    assert_eq!(10_000_000_000_000_u64.to_nominal(&hepburn), "jutchō");
+    assert_eq!(10_000_000_000_000_000_u64.to_nominal(&hepburn), "ikkei");
+    assert_eq!(
+        800_0000_0000_0000_0000_u128.to_nominal(&hepburn),
+        "happyakkei"
+    );
+
+    let formal = Hepburn::new(JapaneseFormal);
+    assert_eq!(100_u32.to_nominal(&formal), "ippyaku");
+    assert_eq!(1_000_u32.to_nominal(&formal), "issen");
+
+    assert_eq!(
+        3_u32.to_nominal(&hepburn.ruby()),
+        "<ruby>三<rt>san</rt></ruby>"
+    );
+}
+
+#[test]
+fn revised_romanization() {
+    use crate::{HangeulFormal, Nominal};
+
+    let korean = RevisedRomanization::new(HangeulFormal::default());
+    assert_eq!(0_u32.to_nominal(&korean), "yeong");
+    assert_eq!(3_u32.to_nominal(&korean), "sam");
+    assert_eq!(7_u32.to_nominal(&korean), "chil");
+    assert_eq!(16_u32.to_nominal(&korean), "ilsimnyuk");
+    assert_eq!(21_u32.to_nominal(&korean), "isibil");
+    assert_eq!(36_u32.to_nominal(&korean), "samsimnyuk");
+    assert_eq!(60_u32.to_nominal(&korean), "yuksip");
+    assert_eq!(10_000_u32.to_nominal(&korean), "ilman");
+    assert_eq!(100_000_000_u32.to_nominal(&korean), "ireok");
+
+    let short = RevisedRomanization::new(HangeulFormal::default().without_leading_one());
+    assert_eq!(1_000_u32.to_nominal(&short), "cheon");
+    assert_eq!(1_000_000_u32.to_nominal(&short), "baengman");
+
+    let spaced = RevisedRomanization::new(HangeulFormal::default().spaced());
+    assert_eq!(100_010_000_u32.to_nominal(&spaced), "ireok ilman");
+
+    assert_eq!(
+        16_u32.to_nominal(&short.ruby()),
+        "<ruby>십<rt>sim</rt>육<rt>nyuk</rt></ruby>"
+    );
+}
@@ -0,0 +1,1017 @@
+use crate::{Error, NominalString, NominalSystem, RuleBasedSystem, UnsignedInteger};
+
+/// Spelled out cardinal numbers in one of the [`SpelloutLanguage`]s, such as
+/// `vingt-et-un` and `einundzwanzig`.
+///
+/// Languages with grammatical gender use their masculine forms.
+///
+/// ```rust
+/// use nominals::{Nominal, SpelloutCardinal, SpelloutLanguage};
+///
+/// let french = SpelloutCardinal::new(SpelloutLanguage::French);
+/// assert_eq!(81_u32.to_nominal(&french), "quatre-vingt-un");
+/// let japanese = SpelloutCardinal::new(SpelloutLanguage::Japanese);
+/// assert_eq!(12_345_u32.to_nominal(&japanese), "一万二千三百四十五");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SpelloutCardinal(Spellout);
+
+impl SpelloutCardinal {
+    /// Returns cardinal numbers spelled out in `language`.
+    ///
+    /// The language's rules are parsed each time this function is called, so
+    /// the returned system should be reused when formatting many nominals.
+    #[must_use]
+    pub fn new(language: SpelloutLanguage) -> Self {
+        Self(Spellout::new(language, false))
+    }
+}
+
+/// Spelled out ordinal numbers in one of the [`SpelloutLanguage`]s, such as
+/// `vingt-et-unième` and `einundzwanzigste`.
+///
+/// Languages with grammatical gender use their masculine forms.
+///
+/// ```rust
+/// use nominals::{Nominal, SpelloutLanguage, SpelloutOrdinal};
+///
+/// let spanish = SpelloutOrdinal::new(SpelloutLanguage::Spanish);
+/// assert_eq!(23_u32.to_nominal(&spanish), "vigésimo tercero");
+/// let korean = SpelloutOrdinal::new(SpelloutLanguage::NativeKorean);
+/// assert_eq!(21_u32.to_nominal(&korean), "스물한째");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SpelloutOrdinal(Spellout);
+
+impl SpelloutOrdinal {
+    /// Returns ordinal numbers spelled out in `language`.
+    ///
+    /// The language's rules are parsed each time this function is called, so
+    /// the returned system should be reused when formatting many nominals.
+    #[must_use]
+    pub fn new(language: SpelloutLanguage) -> Self {
+        Self(Spellout::new(language, true))
+    }
+}
+
+macro_rules! impl_spellout {
+    ($type:ident) => {
+        impl $type {
+            /// Returns the language numbers are spelled out in.
+            #[must_use]
+            pub const fn language(&self) -> SpelloutLanguage {
+                self.0.language
+            }
+        }
+
+        impl<T> NominalSystem<T> for $type
+        where
+            T: UnsignedInteger + TryFrom<u128>,
+        {
+            fn try_format_nominal(&self, nominal: T) -> Result<NominalString, Error<T>> {
+                match &self.0.rules {
+                    Some(rules) => rules.try_format_nominal(nominal),
+                    None => Err(Error::OutOfBounds(nominal)),
+                }
+            }
+        }
+    };
+}
+
+impl_spellout!(SpelloutCardinal);
+impl_spellout!(SpelloutOrdinal);
+
+/// A language supported by [`SpelloutCardinal`] and [`SpelloutOrdinal`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum SpelloutLanguage {
+    /// French: `vingt-et-un`, `vingt-et-unième`.
+    French,
+    /// German: `einundzwanzig`, `einundzwanzigste`.
+    German,
+    /// Spanish: `veintiuno`, `vigésimo primero`.
+    Spanish,
+    /// Italian: `ventuno`, `ventunesimo`.
+    Italian,
+    /// Brazilian Portuguese: `vinte e um`, `vigésimo primeiro`.
+    Portuguese,
+    /// Russian: `двадцать один`, `двадцать первый`.
+    Russian,
+    /// Japanese: `二十一`, `第二十一`.
+    Japanese,
+    /// Sino-Korean numbers: `이십일`, `제이십일`.
+    SinoKorean,
+    /// Native Korean numbers: `스물하나`, `스물한째`.
+    ///
+    /// Native Korean numbers are only used below 100. Larger values are
+    /// written using Sino-Korean hundreds and thousands, and values of 10,000
+    /// or more are written entirely in Sino-Korean numbers.
+    NativeKorean,
+    /// Thai: `ยี่สิบเอ็ด`, `ที่ยี่สิบเอ็ด`.
+    Thai,
+    /// Hindi: `इक्कीस`, `इक्कीसवाँ`.
+    Hindi,
+}
+
+impl SpelloutLanguage {
+    /// Returns the rules of this language, and the names of its cardinal and
+    /// ordinal rule sets.
+    const fn rules(self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            Self::French => (FRENCH, "%spellout-cardinal", "%spellout-ordinal"),
+            Self::German => (GERMAN, "%spellout-cardinal", "%spellout-ordinal"),
+            Self::Spanish => (SPANISH, "%spellout-cardinal", "%spellout-ordinal"),
+            Self::Italian => (ITALIAN, "%spellout-cardinal", "%spellout-ordinal"),
+            Self::Portuguese => (PORTUGUESE, "%spellout-cardinal", "%spellout-ordinal"),
+            Self::Russian => (RUSSIAN, "%spellout-cardinal", "%spellout-ordinal"),
+            Self::Japanese => (JAPANESE, "%spellout-cardinal", "%spellout-ordinal"),
+            Self::SinoKorean => (
+                KOREAN,
+                "%spellout-cardinal-sinokorean",
+                "%spellout-ordinal-sinokorean",
+            ),
+            Self::NativeKorean => (
+                KOREAN,
+                "%spellout-cardinal-native",
+                "%spellout-ordinal-native",
+            ),
+            Self::Thai => (THAI, "%spellout-cardinal", "%spellout-ordinal"),
+            Self::Hindi => (HINDI, "%spellout-cardinal", "%spellout-ordinal"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Spellout {
+    language: SpelloutLanguage,
+    rules: Option<RuleBasedSystem<'static>>,
+}
+
+impl Spellout {
+    fn new(language: SpelloutLanguage, ordinal: bool) -> Self {
+        let (rules, cardinal_set, ordinal_set) = language.rules();
+        // The `bundled_rules` test verifies that every language's rules parse.
+        // If they somehow didn't, every nominal would be out of bounds and
+        // formatted using `Decimal` rather than panicking here.
+        let rules = RuleBasedSystem::parse(rules).ok().and_then(|rules| {
+            rules.with_rule_set(if ordinal { ordinal_set } else { cardinal_set })
+        });
+        Self { language, rules }
+    }
+}
+
+const FRENCH: &str = "
+%spellout-cardinal:
+    0: zéro; 1: un; 2: deux; 3: trois; 4: quatre; 5: cinq; 6: six; 7: sept;
+    8: huit; 9: neuf; 10: dix; 11: onze; 12: douze; 13: treize; 14: quatorze;
+    15: quinze; 16: seize; 17: dix-→→;
+    20: vingt[→%%et-un→]; 30: trente[→%%et-un→]; 40: quarante[→%%et-un→];
+    50: cinquante[→%%et-un→]; 60: soixante[→%%et-un→];
+    70: soixante-dix; 71: soixante-et-onze; 72/20: soixante-→→;
+    80/20: quatre-vingt→%%vingts→;
+    100: cent[ →→]; 200: ←← cent→%%cents→;
+    1000: mille[ →→]; 2000: ←%%leading← mille[ →→];
+    1,000,000: un million[ →→]; 2,000,000: ←← millions[ →→];
+    1,000,000,000: un milliard[ →→]; 2,000,000,000: ←← milliards[ →→];
+    1,000,000,000,000: un billion[ →→]; 2,000,000,000,000: ←← billions[ →→];
+    1,000,000,000,000,000: un billiard[ →→];
+    2,000,000,000,000,000: ←← billiards[ →→];
+%%et-un:
+    1: -et-un; 2: -=%spellout-cardinal=;
+%%vingts:
+    0: s; 1: -=%spellout-cardinal=;
+%%cents:
+    0: s; 1: ' =%spellout-cardinal=;
+%%leading:
+    0: =%spellout-cardinal=;
+    80/20: quatre-vingt[-→%spellout-cardinal→];
+    100: cent[ →→]; 200: ←%spellout-cardinal← cent[ →→];
+%spellout-ordinal:
+    0: zéroième; 1: premier; 2: =%%ordinal=;
+%%ordinal:
+    1: unième; 2: deuxième; 3: troisième; 4: quatrième; 5: cinquième;
+    6: sixième; 7: septième; 8: huitième; 9: neuvième; 10: dixième;
+    11: onzième; 12: douzième; 13: treizième; 14: quatorzième; 15: quinzième;
+    16: seizième; 17: dix-→→;
+    20: vingt→%%ord-t→; 30: trent→%%ord-e→; 40: quarant→%%ord-e→;
+    50: cinquant→%%ord-e→; 60: soixant→%%ord-e→;
+    70: soixante-dixième; 71: soixante-et-onzième; 72/20: soixante-→→;
+    80/20: quatre-vingt→%%ord-vingt→;
+    100: cent→%%ord-cent→; 200: ←%spellout-cardinal← cent→%%ord-cent→;
+    1000: mill→%%ord-mille→; 2000: ←%%leading← mill→%%ord-mille→;
+    1,000,000: un million→%%ord-scale→;
+    2,000,000: ←%spellout-cardinal← million→%%ord-scales→;
+    1,000,000,000: un milliard→%%ord-scale→;
+    2,000,000,000: ←%spellout-cardinal← milliard→%%ord-scales→;
+    1,000,000,000,000: un billion→%%ord-scale→;
+    2,000,000,000,000: ←%spellout-cardinal← billion→%%ord-scales→;
+    1,000,000,000,000,000: un billiard→%%ord-scale→;
+    2,000,000,000,000,000: ←%spellout-cardinal← billiard→%%ord-scales→;
+%%ord-t:
+    0: ième; 1: -et-unième; 2: -=%%ordinal=;
+%%ord-e:
+    0: ième; 1: e-et-unième; 2: e-=%%ordinal=;
+%%ord-vingt:
+    0: ième; 1: -=%%ordinal=;
+%%ord-cent:
+    0: ième; 1: ' =%%ordinal=;
+%%ord-mille:
+    0: ième; 1: e =%%ordinal=;
+%%ord-scale:
+    0: ième; 1: ' =%%ordinal=;
+%%ord-scales:
+    0: ième; 1: s =%%ordinal=;
+";
+
+const GERMAN: &str = "
+%spellout-cardinal:
+    0: null; 1: eins; 2: zwei; 3: drei; 4: vier; 5: fünf; 6: sechs;
+    7: sieben; 8: acht; 9: neun; 10: zehn; 11: elf; 12: zwölf; 13: →→zehn;
+    16: sechzehn; 17: siebzehn; 18: →→zehn;
+    20: [→%%ones→und]zwanzig; 30: [→%%ones→und]dreißig;
+    40: [→%%ones→und]vierzig; 50: [→%%ones→und]fünfzig;
+    60: [→%%ones→und]sechzig; 70: [→%%ones→und]siebzig;
+    80: [→%%ones→und]achtzig; 90: [→%%ones→und]neunzig;
+    100: ←%%ones←hundert[→→]; 1000: ←%%ones←tausend[→→];
+    1,000,000: ←%%millions←[ →→];
+    1,000,000,000: ←%%milliards←[ →→];
+    1,000,000,000,000: ←%%billions←[ →→];
+    1,000,000,000,000,000: ←%%billiards←[ →→];
+%%ones:
+    1: ein; 2: =%spellout-cardinal=;
+    100: ←%%ones←hundert[→%%ones→]; 1000: ←%%ones←tausend[→%%ones→];
+    1,000,000: =%spellout-cardinal=;
+%%millions:
+    1: eine Million; 2: =%spellout-cardinal= Millionen;
+%%milliards:
+    1: eine Milliarde; 2: =%spellout-cardinal= Milliarden;
+%%billions:
+    1: eine Billion; 2: =%spellout-cardinal= Billionen;
+%%billiards:
+    1: eine Billiarde; 2: =%spellout-cardinal= Billiarden;
+%spellout-ordinal:
+    0: nullte; 1: erste; 2: zweite; 3: dritte; 4: =%spellout-cardinal=te;
+    7: siebte; 8: achte; 9: =%spellout-cardinal=te;
+    20: =%spellout-cardinal=ste;
+    100: ←%%ones←hundert→%%ord-ste→; 1000: ←%%ones←tausend→%%ord-ste→;
+    1,000,000: ←%%ones←millionste; 1,000,001: ←%%millions← →→;
+    1,000,000,000: ←%%ones←milliardste; 1,000,000,001: ←%%milliards← →→;
+    1,000,000,000,000: ←%%ones←billionste;
+    1,000,000,000,001: ←%%billions← →→;
+    1,000,000,000,000,000: ←%%ones←billiardste;
+    1,000,000,000,000,001: ←%%billiards← →→;
+%%ord-ste:
+    0: ste; 1: =%spellout-ordinal=;
+";
+
+const SPANISH: &str = "
+%spellout-cardinal:
+    0: cero; 1: uno; 2: dos; 3: tres; 4: cuatro; 5: cinco; 6: seis; 7: siete;
+    8: ocho; 9: nueve; 10: diez; 11: once; 12: doce; 13: trece; 14: catorce;
+    15: quince; 16: dieciséis; 17: diecisiete; 18: dieciocho; 19: diecinueve;
+    20: veinte; 21: veintiuno; 22: veintidós; 23: veintitrés;
+    24: veinticuatro; 25: veinticinco; 26: veintiséis; 27: veintisiete;
+    28: veintiocho; 29: veintinueve;
+    30: treinta[ y →→]; 40: cuarenta[ y →→]; 50: cincuenta[ y →→];
+    60: sesenta[ y →→]; 70: setenta[ y →→]; 80: ochenta[ y →→];
+    90: noventa[ y →→];
+    100: cien; 101: ciento →→; 200: doscientos[ →→]; 300: trescientos[ →→];
+    400: cuatrocientos[ →→]; 500: quinientos[ →→]; 600: seiscientos[ →→];
+    700: setecientos[ →→]; 800: ochocientos[ →→]; 900: novecientos[ →→];
+    1000: mil[ →→]; 2000: ←%%apocope← mil[ →→];
+    1,000,000: un millón[ →→]; 2,000,000: ←%%apocope← millones[ →→];
+    1,000,000,000,000: un billón[ →→];
+    2,000,000,000,000: ←%%apocope← billones[ →→];
+    1,000,000,000,000,000,000: un trillón[ →→];
+    2,000,000,000,000,000,000: ←%%apocope← trillones[ →→];
+    1,000,000,000,000,000,000,000,000: un cuatrillón[ →→];
+    2,000,000,000,000,000,000,000,000: ←%%apocope← cuatrillones[ →→];
+    1,000,000,000,000,000,000,000,000,000,000: un quintillón[ →→];
+    2,000,000,000,000,000,000,000,000,000,000: ←%%apocope← quintillones[ →→];
+    1,000,000,000,000,000,000,000,000,000,000,000,000: un sextillón[ →→];
+    2,000,000,000,000,000,000,000,000,000,000,000,000: ←%%apocope← sextillones[ →→];
+%%apocope:
+    0: =%spellout-cardinal=; 1: un; 2: =%spellout-cardinal=; 21: veintiún;
+    22: =%spellout-cardinal=;
+    30: treinta[ y →→]; 40: cuarenta[ y →→]; 50: cincuenta[ y →→];
+    60: sesenta[ y →→]; 70: setenta[ y →→]; 80: ochenta[ y →→];
+    90: noventa[ y →→];
+    100: cien; 101: ciento →→; 200: doscientos[ →→]; 300: trescientos[ →→];
+    400: cuatrocientos[ →→]; 500: quinientos[ →→]; 600: seiscientos[ →→];
+    700: setecientos[ →→]; 800: ochocientos[ →→]; 900: novecientos[ →→];
+    1000: mil[ →→]; 2000: ←← mil[ →→];
+%spellout-ordinal:
+    0: cero; 1: primero; 2: segundo; 3: tercero; 4: cuarto; 5: quinto;
+    6: sexto; 7: séptimo; 8: octavo; 9: noveno; 10: décimo; 11: undécimo;
+    12: duodécimo; 13: decimo→→;
+    20: vigésimo[ →→]; 30: trigésimo[ →→]; 40: cuadragésimo[ →→];
+    50: quincuagésimo[ →→]; 60: sexagésimo[ →→]; 70: septuagésimo[ →→];
+    80: octogésimo[ →→]; 90: nonagésimo[ →→];
+    100: centésimo[ →→]; 200: ducentésimo[ →→]; 300: tricentésimo[ →→];
+    400: cuadringentésimo[ →→]; 500: quingentésimo[ →→];
+    600: sexcentésimo[ →→]; 700: septingentésimo[ →→];
+    800: octingentésimo[ →→]; 900: noningentésimo[ →→];
+    1000: milésimo[ →→]; 2000: ←%%apocope←milésimo[ →→];
+    1,000,000: millonésimo[ →→]; 2,000,000: ←%%apocope←millonésimo[ →→];
+    1,000,000,000,000: billonésimo[ →→];
+    2,000,000,000,000: ←%%apocope←billonésimo[ →→];
+    1,000,000,000,000,000,000: trillonésimo[ →→];
+    2,000,000,000,000,000,000: ←%%apocope←trillonésimo[ →→];
+    1,000,000,000,000,000,000,000,000: cuatrillonésimo[ →→];
+    2,000,000,000,000,000,000,000,000: ←%%apocope←cuatrillonésimo[ →→];
+    1,000,000,000,000,000,000,000,000,000,000: quintillonésimo[ →→];
+    2,000,000,000,000,000,000,000,000,000,000: ←%%apocope←quintillonésimo[ →→];
+    1,000,000,000,000,000,000,000,000,000,000,000,000: sextillonésimo[ →→];
+    2,000,000,000,000,000,000,000,000,000,000,000,000: ←%%apocope←sextillonésimo[ →→];
+";
+
+const ITALIAN: &str = "
+%spellout-cardinal:
+    0: zero; 1: uno; 2: due; 3: tre; 4: quattro; 5: cinque; 6: sei; 7: sette;
+    8: otto; 9: nove; 10: dieci; 11: undici; 12: dodici; 13: tredici;
+    14: quattordici; 15: quindici; 16: sedici; 17: diciassette; 18: diciotto;
+    19: diciannove;
+    20: vent→%%with-i→; 30: trent→%%with-a→; 40: quarant→%%with-a→;
+    50: cinquant→%%with-a→; 60: sessant→%%with-a→; 70: settant→%%with-a→;
+    80: ottant→%%with-a→; 90: novant→%%with-a→;
+    100: cent→%%with-o→; 200: ←←cent→%%with-o→;
+    1000: mille[→%%accented→]; 2000: ←←mila[→%%accented→];
+    1,000,000: ←%%millions←[ →→];
+    1,000,000,000: ←%%billions←[ →→];
+    1,000,000,000,000: ←%%trillions←[ →→];
+    1,000,000,000,000,000: ←%%quadrillions←[ →→];
+%%with-i:
+    0: i; 1: uno; 2: idue; 3: itré; 4: iquattro; 5: icinque; 6: isei;
+    7: isette; 8: otto; 9: inove;
+%%with-a:
+    0: a; 1: uno; 2: adue; 3: atré; 4: aquattro; 5: acinque; 6: asei;
+    7: asette; 8: otto; 9: anove;
+%%with-o:
+    0: o; 1: o=%spellout-cardinal=; 3: otré; 4: o=%spellout-cardinal=;
+    80: =%spellout-cardinal=; 90: o=%spellout-cardinal=;
+%%accented:
+    0: =%spellout-cardinal=; 3: tré; 4: =%spellout-cardinal=;
+%%millions:
+    1: un milione; 2: =%spellout-cardinal= milioni;
+%%billions:
+    1: un miliardo; 2: =%spellout-cardinal= miliardi;
+%%trillions:
+    1: un bilione; 2: =%spellout-cardinal= bilioni;
+%%quadrillions:
+    1: un biliardo; 2: =%spellout-cardinal= biliardi;
+%spellout-ordinal:
+    0: zeresimo; 1: primo; 2: secondo; 3: terzo; 4: quarto; 5: quinto;
+    6: sesto; 7: settimo; 8: ottavo; 9: nono; 10: decimo; 11: undicesimo;
+    12: dodicesimo; 13: tredicesimo; 14: quattordicesimo; 15: quindicesimo;
+    16: sedicesimo; 17: diciassettesimo; 18: diciottesimo; 19: diciannovesimo;
+    20: vent→%%ord-with-i→; 30: trent→%%ord-with-a→;
+    40: quarant→%%ord-with-a→; 50: cinquant→%%ord-with-a→;
+    60: sessant→%%ord-with-a→; 70: settant→%%ord-with-a→;
+    80: ottant→%%ord-with-a→; 90: novant→%%ord-with-a→;
+    100: cent→%%ord-with-o→; 200: ←%spellout-cardinal←cent→%%ord-with-o→;
+    1000: mill→%%ord-mille→; 2000: ←%spellout-cardinal←mil→%%ord-mila→;
+    1,000,000: ←%%ord-leading←milionesimo;
+    1,000,001: ←%%millions← →%%ord-tail→;
+    1,000,000,000: ←%%ord-leading←miliardesimo;
+    1,000,000,001: ←%%billions← →%%ord-tail→;
+    1,000,000,000,000: ←%%ord-leading←bilionesimo;
+    1,000,000,000,001: ←%%trillions← →%%ord-tail→;
+    1,000,000,000,000,000: ←%%ord-leading←biliardesimo;
+    1,000,000,000,000,001: ←%%quadrillions← →%%ord-tail→;
+%%ord-with-i:
+    0: esimo; 1: unesimo; 2: iduesimo; 3: itreesimo; 4: iquattresimo;
+    5: icinquesimo; 6: iseiesimo; 7: isettesimo; 8: ottesimo; 9: inovesimo;
+%%ord-with-a:
+    0: esimo; 1: unesimo; 2: aduesimo; 3: atreesimo; 4: aquattresimo;
+    5: acinquesimo; 6: aseiesimo; 7: asettesimo; 8: ottesimo; 9: anovesimo;
+%%ord-with-o:
+    0: esimo; 1: o=%%ord-tail=; 80: =%%ord-tail=; 90: o=%%ord-tail=;
+%%ord-mille:
+    0: esimo; 1: e=%%ord-tail=;
+%%ord-mila:
+    0: lesimo; 1: a=%%ord-tail=;
+%%ord-tail:
+    1: unesimo; 2: duesimo; 3: treesimo; 4: quattresimo; 5: cinquesimo;
+    6: seiesimo; 7: settesimo; 8: ottesimo; 9: novesimo;
+    10: =%spellout-ordinal=;
+%%ord-leading:
+    1: ; 2: =%spellout-cardinal=;
+";
+
+const PORTUGUESE: &str = "
+%spellout-cardinal:
+    0: zero; 1: um; 2: dois; 3: três; 4: quatro; 5: cinco; 6: seis; 7: sete;
+    8: oito; 9: nove; 10: dez; 11: onze; 12: doze; 13: treze; 14: catorze;
+    15: quinze; 16: dezesseis; 17: dezessete; 18: dezoito; 19: dezenove;
+    20: vinte[ e →→]; 30: trinta[ e →→]; 40: quarenta[ e →→];
+    50: cinquenta[ e →→]; 60: sessenta[ e →→]; 70: setenta[ e →→];
+    80: oitenta[ e →→]; 90: noventa[ e →→];
+    100: cem; 101: cento e →→; 200: duzentos[ e →→]; 300: trezentos[ e →→];
+    400: quatrocentos[ e →→]; 500: quinhentos[ e →→];
+    600: seiscentos[ e →→]; 700: setecentos[ e →→];
+    800: oitocentos[ e →→]; 900: novecentos[ e →→];
+    1000: mil[→%%with-e→]; 2000: ←← mil[→%%with-e→];
+    1,000,000: um milhão[→%%with-e→];
+    2,000,000: ←← milhões[→%%with-e→];
+    1,000,000,000: um bilhão[→%%with-e→];
+    2,000,000,000: ←← bilhões[→%%with-e→];
+    1,000,000,000,000: um trilhão[→%%with-e→];
+    2,000,000,000,000: ←← trilhões[→%%with-e→];
+    1,000,000,000,000,000: um quatrilhão[→%%with-e→];
+    2,000,000,000,000,000: ←← quatrilhões[→%%with-e→];
+    1,000,000,000,000,000,000: um quintilhão[→%%with-e→];
+    2,000,000,000,000,000,000: ←← quintilhões[→%%with-e→];
+%%with-e:
+    1: ' e =%spellout-cardinal=;
+    100: ' e cem; 101: ' cento e →%spellout-cardinal→;
+    200: ' e duzentos; 201: ' duzentos e →%spellout-cardinal→;
+    300: ' e trezentos; 301: ' trezentos e →%spellout-cardinal→;
+    400: ' e quatrocentos; 401: ' quatrocentos e →%spellout-cardinal→;
+    500: ' e quinhentos; 501: ' quinhentos e →%spellout-cardinal→;
+    600: ' e seiscentos; 601: ' seiscentos e →%spellout-cardinal→;
+    700: ' e setecentos; 701: ' setecentos e →%spellout-cardinal→;
+    800: ' e oitocentos; 801: ' oitocentos e →%spellout-cardinal→;
+    900: ' e novecentos; 901: ' novecentos e →%spellout-cardinal→;
+    1000: ' =%spellout-cardinal=;
+%spellout-ordinal:
+    0: zero; 1: primeiro; 2: segundo; 3: terceiro; 4: quarto; 5: quinto;
+    6: sexto; 7: sétimo; 8: oitavo; 9: nono; 10: décimo; 11: décimo →→;
+    20: vigésimo[ →→]; 30: trigésimo[ →→]; 40: quadragésimo[ →→];
+    50: quinquagésimo[ →→]; 60: sexagésimo[ →→]; 70: septuagésimo[ →→];
+    80: octogésimo[ →→]; 90: nonagésimo[ →→];
+    100: centésimo[ →→]; 200: ducentésimo[ →→]; 300: trecentésimo[ →→];
+    400: quadringentésimo[ →→]; 500: quingentésimo[ →→];
+    600: sexcentésimo[ →→]; 700: septingentésimo[ →→];
+    800: octingentésimo[ →→]; 900: nongentésimo[ →→];
+    1000: milésimo[ →→]; 2000: ←%spellout-cardinal← milésimo[ →→];
+    1,000,000: milionésimo[ →→];
+    2,000,000: ←%spellout-cardinal← milionésimo[ →→];
+    1,000,000,000: bilionésimo[ →→];
+    2,000,000,000: ←%spellout-cardinal← bilionésimo[ →→];
+    1,000,000,000,000: trilionésimo[ →→];
+    2,000,000,000,000: ←%spellout-cardinal← trilionésimo[ →→];
+    1,000,000,000,000,000: quatrilionésimo[ →→];
+    2,000,000,000,000,000: ←%spellout-cardinal← quatrilionésimo[ →→];
+    1,000,000,000,000,000,000: quintilionésimo[ →→];
+    2,000,000,000,000,000,000: ←%spellout-cardinal← quintilionésimo[ →→];
+";
+
+const RUSSIAN: &str = "
+%spellout-cardinal:
+    0: ноль; 1: один; 2: два; 3: три; 4: четыре; 5: пять; 6: шесть; 7: семь;
+    8: восемь; 9: девять; 10: десять; 11: одиннадцать; 12: двенадцать;
+    13: тринадцать; 14: четырнадцать; 15: пятнадцать; 16: шестнадцать;
+    17: семнадцать; 18: восемнадцать; 19: девятнадцать;
+    20: двадцать[ →→]; 30: тридцать[ →→]; 40: сорок[ →→];
+    50: пятьдесят[ →→]; 60: шестьдесят[ →→]; 70: семьдесят[ →→];
+    80: восемьдесят[ →→]; 90: девяносто[ →→];
+    100: сто[ →→]; 200: двести[ →→]; 300: триста[ →→]; 400: четыреста[ →→];
+    500: пятьсот[ →→]; 600: шестьсот[ →→]; 700: семьсот[ →→];
+    800: восемьсот[ →→]; 900: девятьсот[ →→];
+    1000: ←%%feminine← тысяч←%%feminine-ending←[ →→];
+    1,000,000: ←← миллион←%%masculine-ending←[ →→];
+    1,000,000,000: ←← миллиард←%%masculine-ending←[ →→];
+    1,000,000,000,000: ←← триллион←%%masculine-ending←[ →→];
+    1,000,000,000,000,000: ←← квадриллион←%%masculine-ending←[ →→];
+    1,000,000,000,000,000,000: ←← квинтиллион←%%masculine-ending←[ →→];
+%%feminine:
+    0: ноль; 1: одна; 2: две; 3: =%spellout-cardinal=;
+    20: двадцать[ →→]; 30: тридцать[ →→]; 40: сорок[ →→];
+    50: пятьдесят[ →→]; 60: шестьдесят[ →→]; 70: семьдесят[ →→];
+    80: восемьдесят[ →→]; 90: девяносто[ →→];
+    100: сто[ →→]; 200: двести[ →→]; 300: триста[ →→]; 400: четыреста[ →→];
+    500: пятьсот[ →→]; 600: шестьсот[ →→]; 700: семьсот[ →→];
+    800: восемьсот[ →→]; 900: девятьсот[ →→];
+    1000: =%spellout-cardinal=;
+%%feminine-ending:
+    0: ; 1: а; 2: и; 5: ; 20: →→; 100: →→; 1000: →→;
+%%masculine-ending:
+    0: ов; 1: ; 2: а; 5: ов; 20: →→; 100: →→; 1000: →→;
+%spellout-ordinal:
+    0: нулевой; 1: первый; 2: второй; 3: третий; 4: четвёртый; 5: пятый;
+    6: шестой; 7: седьмой; 8: восьмой; 9: девятый; 10: десятый;
+    11: одиннадцатый; 12: двенадцатый; 13: тринадцатый;
+    14: четырнадцатый; 15: пятнадцатый; 16: шестнадцатый;
+    17: семнадцатый; 18: восемнадцатый; 19: девятнадцатый;
+    20: двадцатый; 21: двадцать →→; 30: тридцатый; 31: тридцать →→;
+    40: сороковой; 41: сорок →→; 50: пятидесятый; 51: пятьдесят →→;
+    60: шестидесятый; 61: шестьдесят →→; 70: семидесятый; 71: семьдесят →→;
+    80: восьмидесятый; 81: восемьдесят →→; 90: девяностый; 91: девяносто →→;
+    100: сотый; 101: сто →→; 200: двухсотый; 201: двести →→;
+    300: трёхсотый; 301: триста →→; 400: четырёхсотый; 401: четыреста →→;
+    500: пятисотый; 501: пятьсот →→; 600: шестисотый; 601: шестьсот →→;
+    700: семисотый; 701: семьсот →→; 800: восьмисотый; 801: восемьсот →→;
+    900: девятисотый; 901: девятьсот →→;
+    1000: ←%%genitive←тысячный;
+    1001: ←%%thousands← →→;
+    1,000,000: ←%%genitive←миллионный;
+    1,000,001: ←%spellout-cardinal← миллион←%%masculine-ending← →→;
+    1,000,000,000: ←%%genitive←миллиардный;
+    1,000,000,001: ←%spellout-cardinal← миллиард←%%masculine-ending← →→;
+    1,000,000,000,000: ←%%genitive←триллионный;
+    1,000,000,000,001: ←%spellout-cardinal← триллион←%%masculine-ending← →→;
+    1,000,000,000,000,000: ←%%genitive←квадриллионный;
+    1,000,000,000,000,001: ←%spellout-cardinal← квадриллион←%%masculine-ending← →→;
+    1,000,000,000,000,000,000: ←%%genitive←квинтиллионный;
+    1,000,000,000,000,000,001: ←%spellout-cardinal← квинтиллион←%%masculine-ending← →→;
+%%thousands:
+    1: тысяча; 2: =%%feminine= тысяч=%%feminine-ending=;
+%%genitive:
+    1: ; 2: двух; 3: трёх; 4: четырёх; 5: пяти; 6: шести; 7: семи; 8: восьми;
+    9: девяти; 10: десяти; 11: одиннадцати; 12: двенадцати; 13: тринадцати;
+    14: четырнадцати; 15: пятнадцати; 16: шестнадцати; 17: семнадцати;
+    18: восемнадцати; 19: девятнадцати;
+    20: двадцати[→%%genitive-ones→]; 30: тридцати[→%%genitive-ones→];
+    40: сорока[→%%genitive-ones→]; 50: пятидесяти[→%%genitive-ones→];
+    60: шестидесяти[→%%genitive-ones→]; 70: семидесяти[→%%genitive-ones→];
+    80: восьмидесяти[→%%genitive-ones→]; 90: девяноста[→%%genitive-ones→];
+    100: сто[→%%genitive-ones→]; 200: двухсот[→%%genitive-ones→];
+    300: трёхсот[→%%genitive-ones→]; 400: четырёхсот[→%%genitive-ones→];
+    500: пятисот[→%%genitive-ones→]; 600: шестисот[→%%genitive-ones→];
+    700: семисот[→%%genitive-ones→]; 800: восьмисот[→%%genitive-ones→];
+    900: девятисот[→%%genitive-ones→];
+    1000: ←←тысяче[→%%genitive-ones→];
+%%genitive-ones:
+    1: одно; 2: =%%genitive=;
+";
+
+const JAPANESE: &str = "
+%spellout-cardinal:
+    0: 〇; 1: 一; 2: 二; 3: 三; 4: 四; 5: 五; 6: 六; 7: 七; 8: 八; 9: 九;
+    10: 十[→→]; 20: ←←十[→→]; 100: 百[→→]; 200: ←←百[→→];
+    1000: 千[→→]; 2000: ←←千[→→];
+    10,000: ←←万[→→];
+    100,000,000: ←←億[→→];
+    1,000,000,000,000: ←←兆[→→];
+    10,000,000,000,000,000: ←←京[→→];
+    100,000,000,000,000,000,000: ←←垓[→→];
+    1,000,000,000,000,000,000,000,000: ←←秭[→→];
+    10,000,000,000,000,000,000,000,000,000: ←←穣[→→];
+    100,000,000,000,000,000,000,000,000,000,000: ←←溝[→→];
+    1,000,000,000,000,000,000,000,000,000,000,000,000: ←←澗[→→];
+%spellout-ordinal:
+    0: 第=%spellout-cardinal=;
+";
+
+const KOREAN: &str = "
+%spellout-cardinal-sinokorean:
+    0: 영; 1: 일; 2: 이; 3: 삼; 4: 사; 5: 오; 6: 육; 7: 칠; 8: 팔; 9: 구;
+    10: 십[→→]; 20: ←←십[→→]; 100: 백[→→]; 200: ←←백[→→];
+    1000: 천[→→]; 2000: ←←천[→→];
+    10,000: 만[ →→]; 20,000: ←←만[ →→];
+    100,000,000: ←←억[ →→];
+    1,000,000,000,000: ←←조[ →→];
+    10,000,000,000,000,000: ←←경[ →→];
+    100,000,000,000,000,000,000: ←←해[ →→];
+    1,000,000,000,000,000,000,000,000: ←←자[ →→];
+    10,000,000,000,000,000,000,000,000,000: ←←양[ →→];
+    100,000,000,000,000,000,000,000,000,000,000: ←←구[ →→];
+    1,000,000,000,000,000,000,000,000,000,000,000,000: ←←간[ →→];
+%spellout-cardinal-native:
+    0: 영; 1: 하나; 2: 둘; 3: 셋; 4: 넷; 5: 다섯; 6: 여섯; 7: 일곱; 8: 여덟;
+    9: 아홉; 10: 열[→→]; 20: 스물[→→]; 30: 서른[→→]; 40: 마흔[→→];
+    50: 쉰[→→]; 60: 예순[→→]; 70: 일흔[→→]; 80: 여든[→→]; 90: 아흔[→→];
+    100: 백[→→]; 200: ←%spellout-cardinal-sinokorean←백[→→];
+    1000: 천[→→]; 2000: ←%spellout-cardinal-sinokorean←천[→→];
+    10,000: =%spellout-cardinal-sinokorean=;
+%spellout-ordinal-sinokorean:
+    0: 제=%spellout-cardinal-sinokorean=;
+%spellout-ordinal-native:
+    0: 영째; 1: 첫째; 2: 둘째; 3: 셋째; 4: 넷째; 5: 다섯째; 6: 여섯째;
+    7: 일곱째; 8: 여덟째; 9: 아홉째; 10: 열→%%ord-native→; 20: 스무째;
+    21: 스물→%%ord-native→; 30: 서른→%%ord-native→; 40: 마흔→%%ord-native→;
+    50: 쉰→%%ord-native→; 60: 예순→%%ord-native→; 70: 일흔→%%ord-native→;
+    80: 여든→%%ord-native→; 90: 아흔→%%ord-native→;
+    100: 백→%%ord-native→;
+    200: ←%spellout-cardinal-sinokorean←백→%%ord-native→;
+    1000: 천→%%ord-native→;
+    2000: ←%spellout-cardinal-sinokorean←천→%%ord-native→;
+    10,000: =%spellout-cardinal-sinokorean= 번째;
+%%ord-native:
+    0: 째; 1: 한째; 2: 두째; 3: 셋째; 4: 넷째; 5: =%spellout-ordinal-native=;
+";
+
+const THAI: &str = "
+%spellout-cardinal:
+    0: ศูนย์; 1: หนึ่ง; 2: สอง; 3: สาม; 4: สี่; 5: ห้า; 6: หก; 7: เจ็ด;
+    8: แปด; 9: เก้า;
+    10: สิบ[→%%alt-ones→]; 20: ยี่สิบ[→%%alt-ones→];
+    30: ←←สิบ[→%%alt-ones→];
+    100: ←←ร้อย[→%%alt-ones→]; 1000: ←←พัน[→%%alt-ones→];
+    10,000: ←←หมื่น[→%%alt-ones→]; 100,000: ←←แสน[→%%alt-ones→];
+    1,000,000: ←←ล้าน[→%%alt-ones→];
+%%alt-ones:
+    1: เอ็ด; 2: =%spellout-cardinal=;
+%spellout-ordinal:
+    0: ที่=%spellout-cardinal=;
+";
+
+const HINDI: &str = "
+%spellout-cardinal:
+    0: शून्य; 1: एक; 2: दो; 3: तीन; 4: चार; 5: पाँच; 6: छह; 7: सात; 8: आठ;
+    9: नौ; 10: दस; 11: ग्यारह; 12: बारह; 13: तेरह; 14: चौदह; 15: पंद्रह;
+    16: सोलह; 17: सत्रह; 18: अठारह; 19: उन्नीस; 20: बीस; 21: इक्कीस;
+    22: बाईस; 23: तेईस; 24: चौबीस; 25: पच्चीस; 26: छब्बीस; 27: सत्ताईस;
+    28: अट्ठाईस; 29: उनतीस; 30: तीस; 31: इकतीस; 32: बत्तीस; 33: तैंतीस;
+    34: चौंतीस; 35: पैंतीस; 36: छत्तीस; 37: सैंतीस; 38: अड़तीस; 39: उनतालीस;
+    40: चालीस; 41: इकतालीस; 42: बयालीस; 43: तैंतालीस; 44: चौवालीस;
+    45: पैंतालीस; 46: छियालीस; 47: सैंतालीस; 48: अड़तालीस; 49: उनचास;
+    50: पचास; 51: इक्यावन; 52: बावन; 53: तिरेपन; 54: चौवन; 55: पचपन;
+    56: छप्पन; 57: सत्तावन; 58: अट्ठावन; 59: उनसठ; 60: साठ; 61: इकसठ;
+    62: बासठ; 63: तिरेसठ; 64: चौंसठ; 65: पैंसठ; 66: छियासठ; 67: सड़सठ;
+    68: अड़सठ; 69: उनहत्तर; 70: सत्तर; 71: इकहत्तर; 72: बहत्तर; 73: तिहत्तर;
+    74: चौहत्तर; 75: पचहत्तर; 76: छिहत्तर; 77: सतहत्तर; 78: अठहत्तर;
+    79: उन्यासी; 80: अस्सी; 81: इक्यासी; 82: बयासी; 83: तिरासी; 84: चौरासी;
+    85: पचासी; 86: छियासी; 87: सत्तासी; 88: अट्ठासी; 89: नवासी; 90: नब्बे;
+    91: इक्यानबे; 92: बानबे; 93: तिरानबे; 94: चौरानबे; 95: पंचानबे;
+    96: छियानबे; 97: सत्तानबे; 98: अट्ठानबे; 99: निन्यानबे;
+    100: ←← सौ[ →→]; 1000: ←← हज़ार[ →→]; 100,000: ←← लाख[ →→];
+    10,000,000: ←← करोड़[ →→]; 1,000,000,000: ←← अरब[ →→];
+    100,000,000,000: ←← खरब[ →→];
+%spellout-ordinal:
+    0: =%spellout-cardinal=वाँ; 1: पहला; 2: दूसरा; 3: तीसरा; 4: चौथा;
+    5: =%spellout-cardinal=वाँ; 6: छठा; 7: =%spellout-cardinal=वाँ;
+";
+
+#[test]
+fn bundled_rules() {
+    for language in [
+        SpelloutLanguage::French,
+        SpelloutLanguage::German,
+        SpelloutLanguage::Spanish,
+        SpelloutLanguage::Italian,
+        SpelloutLanguage::Portuguese,
+        SpelloutLanguage::Russian,
+        SpelloutLanguage::Japanese,
+        SpelloutLanguage::SinoKorean,
+        SpelloutLanguage::NativeKorean,
+        SpelloutLanguage::Thai,
+        SpelloutLanguage::Hindi,
+    ] {
+        let (rules, cardinal_set, ordinal_set) = language.rules();
+        let rules = RuleBasedSystem::parse(rules)
+            .unwrap_or_else(|err| panic!("{language:?} rules are invalid: {err:?}"));
+        for set in [cardinal_set, ordinal_set] {
+            assert!(
+                rules.clone().with_rule_set(set).is_some(),
+                "{language:?} is missing {set}"
+            );
+        }
+        assert!(Spellout::new(language, false).rules.is_some());
+        assert!(Spellout::new(language, true).rules.is_some());
+    }
+}
+
+#[cfg(test)]
+fn assert_spellout(
+    language: SpelloutLanguage,
+    cardinals: &[(u64, &str)],
+    ordinals: &[(u64, &str)],
+) {
+    use crate::Nominal;
+
+    let cardinal = SpelloutCardinal::new(language);
+    assert_eq!(cardinal.language(), language);
+    for &(n, expected) in cardinals {
+        assert_eq!(n.to_nominal(&cardinal), expected);
+    }
+    let ordinal = SpelloutOrdinal::new(language);
+    assert_eq!(ordinal.language(), language);
+    for &(n, expected) in ordinals {
+        assert_eq!(n.to_nominal(&ordinal), expected);
+    }
+}
+
+#[test]
+fn french() {
+    assert_spellout(
+        SpelloutLanguage::French,
+        &[
+            (0, "zéro"),
+            (17, "dix-sept"),
+            (21, "vingt-et-un"),
+            (71, "soixante-et-onze"),
+            (80, "quatre-vingts"),
+            (91, "quatre-vingt-onze"),
+            (101, "cent un"),
+            (200, "deux cents"),
+            (201, "deux cent un"),
+            (80_000, "quatre-vingt mille"),
+            (200_000, "deux cent mille"),
+            (2_000_000, "deux millions"),
+            (
+                1_234_567,
+                "un million deux cent trente-quatre mille cinq cent soixante-sept",
+            ),
+        ],
+        &[
+            (1, "premier"),
+            (5, "cinquième"),
+            (9, "neuvième"),
+            (21, "vingt-et-unième"),
+            (30, "trentième"),
+            (31, "trente-et-unième"),
+            (72, "soixante-douzième"),
+            (80, "quatre-vingtième"),
+            (81, "quatre-vingt-unième"),
+            (101, "cent unième"),
+            (1_000, "millième"),
+            (2_000, "deux millième"),
+            (2_000_001, "deux millions unième"),
+        ],
+    );
+}
+
+#[test]
+fn german() {
+    assert_spellout(
+        SpelloutLanguage::German,
+        &[
+            (0, "null"),
+            (1, "eins"),
+            (16, "sechzehn"),
+            (21, "einundzwanzig"),
+            (101, "einhunderteins"),
+            (1_000, "eintausend"),
+            (101_000, "einhunderteintausend"),
+            (1_000_000, "eine Million"),
+            (
+                2_345_678,
+                "zwei Millionen dreihundertfünfundvierzigtausendsechshundertachtundsiebzig",
+            ),
+        ],
+        &[
+            (1, "erste"),
+            (3, "dritte"),
+            (7, "siebte"),
+            (8, "achte"),
+            (19, "neunzehnte"),
+            (20, "zwanzigste"),
+            (21, "einundzwanzigste"),
+            (100, "einhundertste"),
+            (1_001, "eintausenderste"),
+            (2_000_000, "zweimillionste"),
+            (1_000_003, "eine Million dritte"),
+        ],
+    );
+}
+
+#[test]
+fn spanish() {
+    assert_spellout(
+        SpelloutLanguage::Spanish,
+        &[
+            (1, "uno"),
+            (16, "dieciséis"),
+            (21, "veintiuno"),
+            (31, "treinta y uno"),
+            (100, "cien"),
+            (101, "ciento uno"),
+            (500, "quinientos"),
+            (21_000, "veintiún mil"),
+            (1_000_000, "un millón"),
+            (1_000_000_000, "mil millones"),
+            (31_000_000, "treinta y un millones"),
+        ],
+        &[
+            (1, "primero"),
+            (3, "tercero"),
+            (11, "undécimo"),
+            (13, "decimotercero"),
+            (23, "vigésimo tercero"),
+            (100, "centésimo"),
+            (345, "tricentésimo cuadragésimo quinto"),
+            (2_000, "dosmilésimo"),
+            (1_000_000, "millonésimo"),
+        ],
+    );
+}
+
+#[test]
+fn italian() {
+    assert_spellout(
+        SpelloutLanguage::Italian,
+        &[
+            (1, "uno"),
+            (21, "ventuno"),
+            (23, "ventitré"),
+            (28, "ventotto"),
+            (38, "trentotto"),
+            (101, "centouno"),
+            (180, "centottanta"),
+            (1_003, "milletré"),
+            (2_000, "duemila"),
+            (1_000_000, "un milione"),
+            (3_000_000, "tre milioni"),
+        ],
+        &[
+            (1, "primo"),
+            (10, "decimo"),
+            (11, "undicesimo"),
+            (20, "ventesimo"),
+            (21, "ventunesimo"),
+            (23, "ventitreesimo"),
+            (33, "trentatreesimo"),
+            (101, "centounesimo"),
+            (1_000, "millesimo"),
+            (2_000, "duemillesimo"),
+            (2_001, "duemilaunesimo"),
+            (2_000_000, "duemilionesimo"),
+        ],
+    );
+}
+
+#[test]
+fn portuguese() {
+    assert_spellout(
+        SpelloutLanguage::Portuguese,
+        &[
+            (1, "um"),
+            (16, "dezesseis"),
+            (21, "vinte e um"),
+            (100, "cem"),
+            (123, "cento e vinte e três"),
+            (1_001, "mil e um"),
+            (1_100, "mil e cem"),
+            (1_234, "mil duzentos e trinta e quatro"),
+            (2_000_000, "dois milhões"),
+        ],
+        &[
+            (1, "primeiro"),
+            (11, "décimo primeiro"),
+            (21, "vigésimo primeiro"),
+            (100, "centésimo"),
+            (999, "nongentésimo nonagésimo nono"),
+            (1_000, "milésimo"),
+        ],
+    );
+}
+
+#[test]
+fn russian() {
+    assert_spellout(
+        SpelloutLanguage::Russian,
+        &[
+            (0, "ноль"),
+            (21, "двадцать один"),
+            (1_000, "одна тысяча"),
+            (2_000, "две тысячи"),
+            (5_000, "пять тысяч"),
+            (11_000, "одиннадцать тысяч"),
+            (21_000, "двадцать одна тысяча"),
+            (1_000_000, "один миллион"),
+            (3_000_000, "три миллиона"),
+            (12_000_000, "двенадцать миллионов"),
+            (
+                1_234_567,
+                "один миллион двести тридцать четыре тысячи пятьсот шестьдесят семь",
+            ),
+        ],
+        &[
+            (0, "нулевой"),
+            (1, "первый"),
+            (21, "двадцать первый"),
+            (40, "сороковой"),
+            (100, "сотый"),
+            (123, "сто двадцать третий"),
+            (1_000, "тысячный"),
+            (1_001, "тысяча первый"),
+            (2_000, "двухтысячный"),
+            (2_001, "две тысячи первый"),
+            (21_000, "двадцатиоднотысячный"),
+            (1_000_000, "миллионный"),
+            (5_000_004, "пять миллионов четвёртый"),
+        ],
+    );
+}
+
+#[test]
+fn japanese() {
+    assert_spellout(
+        SpelloutLanguage::Japanese,
+        &[
+            (0, "〇"),
+            (10, "十"),
+            (21, "二十一"),
+            (1_000, "千"),
+            (10_000, "一万"),
+            (12_345, "一万二千三百四十五"),
+            (100_000_000, "一億"),
+            (1_000_000_000_001, "一兆一"),
+        ],
+        &[(1, "第一"), (10, "第十"), (21, "第二十一")],
+    );
+}
+
+#[test]
+fn sino_korean() {
+    assert_spellout(
+        SpelloutLanguage::SinoKorean,
+        &[
+            (0, "영"),
+            (16, "십육"),
+            (21, "이십일"),
+            (10_000, "만"),
+            (12_345, "만 이천삼백사십오"),
+            (100_000_000, "일억"),
+        ],
+        &[(1, "제일"), (21, "제이십일")],
+    );
+}
+
+#[test]
+fn native_korean() {
+    assert_spellout(
+        SpelloutLanguage::NativeKorean,
+        &[
+            (1, "하나"),
+            (10, "열"),
+            (20, "스물"),
+            (21, "스물하나"),
+            (99, "아흔아홉"),
+            (101, "백하나"),
+            (12_345, "만 이천삼백사십오"),
+        ],
+        &[
+            (1, "첫째"),
+            (2, "둘째"),
+            (10, "열째"),
+            (11, "열한째"),
+            (12, "열두째"),
+            (20, "스무째"),
+            (21, "스물한째"),
+            (99, "아흔아홉째"),
+            (101, "백한째"),
+            (10_000, "만 번째"),
+        ],
+    );
+}
+
+#[test]
+fn thai() {
+    assert_spellout(
+        SpelloutLanguage::Thai,
+        &[
+            (0, "ศูนย์"),
+            (11, "สิบเอ็ด"),
+            (20, "ยี่สิบ"),
+            (21, "ยี่สิบเอ็ด"),
+            (101, "หนึ่งร้อยเอ็ด"),
+            (1_000_000, "หนึ่งล้าน"),
+            (1_000_000_000_000, "หนึ่งล้านล้าน"),
+        ],
+        &[(1, "ที่หนึ่ง"), (21, "ที่ยี่สิบเอ็ด")],
+    );
+}
+
+#[test]
+fn hindi() {
+    assert_spellout(
+        SpelloutLanguage::Hindi,
+        &[
+            (0, "शून्य"),
+            (21, "इक्कीस"),
+            (99, "निन्यानबे"),
+            (101, "एक सौ एक"),
+            (100_000, "एक लाख"),
+            (1_234_567, "बारह लाख चौंतीस हज़ार पाँच सौ सड़सठ"),
+            (10_000_000, "एक करोड़"),
+        ],
+        &[
+            (1, "पहला"),
+            (2, "दूसरा"),
+            (4, "चौथा"),
+            (5, "पाँचवाँ"),
+            (6, "छठा"),
+            (21, "इक्कीसवाँ"),
+            (100, "एक सौवाँ"),
+        ],
+    );
+}
+
+#[test]
+fn all_values() {
+    use crate::Nominal;
+
+    for language in [
+        SpelloutLanguage::French,
+        SpelloutLanguage::German,
+        SpelloutLanguage::Spanish,
+        SpelloutLanguage::Italian,
+        SpelloutLanguage::Portuguese,
+        SpelloutLanguage::Russian,
+        SpelloutLanguage::Japanese,
+        SpelloutLanguage::SinoKorean,
+        SpelloutLanguage::NativeKorean,
+        SpelloutLanguage::Thai,
+        SpelloutLanguage::Hindi,
+    ] {
+        let cardinal = SpelloutCardinal::new(language);
+        let ordinal = SpelloutOrdinal::new(language);
+        for n in (0_u128..2_000).chain([u128::MAX / 7, u128::MAX]) {
+            assert!(n.try_to_nominal(&cardinal).is_ok(), "{language:?} {n}");
+            assert!(n.try_to_nominal(&ordinal).is_ok(), "{language:?} {n}");
+        }
+        assert!(u8::MAX.try_to_nominal(&cardinal).is_ok());
+    }
+}
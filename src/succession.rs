@@ -0,0 +1,97 @@
+use crate::{Error, NominalString, OutOfMemoryError};
+
+/// A nominal system that can find the nominals adjacent to a formatted
+/// nominal.
+///
+/// Each function accepts a nominal in the exact form this system formats it
+/// and returns the adjacent nominal without requiring the caller to know the
+/// nominal's value.
+///
+/// ```rust
+/// use nominals::{DigitCollection, Hebrew, LetterUpper, NominalSuccession, SuccessionError};
+///
+/// assert_eq!(LetterUpper.next_nominal("AZZ").unwrap(), "BAA");
+/// assert_eq!(LetterUpper.prev_nominal("AA").unwrap(), "Z");
+/// assert_eq!(
+///     LetterUpper.one_based().prev_nominal("A"),
+///     Err(SuccessionError::NoPredecessor)
+/// );
+/// assert_eq!(Hebrew.next_nominal("יד").unwrap(), "ט״ו");
+/// ```
+pub trait NominalSuccession {
+    /// Returns the nominal that follows `nominal`.
+    ///
+    /// # Errors
+    ///
+    /// - [`SuccessionError::InvalidNominal`]: `nominal` is not a nominal this
+    ///   system formats.
+    /// - [`SuccessionError::NoSuccessor`]: `nominal` is the last nominal this
+    ///   system can represent.
+    /// - [`SuccessionError::OutOfMemory`]: The next nominal can't be formatted
+    ///   with the available memory.
+    fn next_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError>;
+
+    /// Returns the nominal that precedes `nominal`.
+    ///
+    /// # Errors
+    ///
+    /// - [`SuccessionError::InvalidNominal`]: `nominal` is not a nominal this
+    ///   system formats.
+    /// - [`SuccessionError::NoPredecessor`]: `nominal` is the first nominal
+    ///   this system can represent.
+    /// - [`SuccessionError::OutOfMemory`]: The previous nominal can't be
+    ///   formatted with the available memory.
+    fn prev_nominal(&self, nominal: &str) -> Result<NominalString, SuccessionError>;
+}
+
+/// Errors that can arise from finding adjacent nominals.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum SuccessionError {
+    /// The string is not a nominal this system formats.
+    InvalidNominal,
+    /// The nominal is the first nominal this system can represent.
+    NoPredecessor,
+    /// The nominal is the last nominal this system can represent.
+    NoSuccessor,
+    /// The adjacent nominal can't be formatted with the available memory.
+    OutOfMemory,
+}
+
+impl From<OutOfMemoryError> for SuccessionError {
+    fn from(_: OutOfMemoryError) -> Self {
+        Self::OutOfMemory
+    }
+}
+
+/// Finds an adjacent nominal by parsing `nominal` using `parse` and formatting
+/// the adjacent value using `format`.
+///
+/// Parsing only needs to be correct for valid nominals: `nominal` is rejected
+/// unless formatting the parsed value reproduces it exactly.
+pub(crate) fn step_parsed(
+    nominal: &str,
+    forward: bool,
+    parse: impl FnOnce(&str) -> Option<u128>,
+    format: impl Fn(u128) -> Result<NominalString, Error<u128>>,
+) -> Result<NominalString, SuccessionError> {
+    let value = parse(nominal).ok_or(SuccessionError::InvalidNominal)?;
+    match format(value) {
+        Ok(formatted) if formatted == nominal => {}
+        Ok(_) | Err(Error::NoZeroSymbol | Error::OutOfBounds(_)) => {
+            return Err(SuccessionError::InvalidNominal)
+        }
+        Err(Error::OutOfMemory(_)) => return Err(SuccessionError::OutOfMemory),
+    }
+
+    let adjacent = if forward {
+        value.checked_add(1).ok_or(SuccessionError::NoSuccessor)?
+    } else {
+        value.checked_sub(1).ok_or(SuccessionError::NoPredecessor)?
+    };
+    format(adjacent).map_err(|err| match err {
+        Error::OutOfMemory(_) => SuccessionError::OutOfMemory,
+        Error::OutOfBounds(_) if forward => SuccessionError::NoSuccessor,
+        Error::NoZeroSymbol | Error::OutOfBounds(_) => SuccessionError::NoPredecessor,
+    })
+}
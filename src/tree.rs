@@ -0,0 +1,453 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{Error, NominalString, NominalSystem, Outline, UnsignedInteger};
+
+/// An instruction that modifies a counter when entering an element in a
+/// [`CounterTree`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CounterInstruction<'a, T> {
+    /// Instantiates a new counter with the given name and value. This is
+    /// equivalent to the CSS `counter-reset` property.
+    Reset(&'a str, T),
+    /// Adds a value to the innermost counter with the given name. This is
+    /// equivalent to the CSS `counter-increment` property.
+    Increment(&'a str, T),
+    /// Subtracts a value from the innermost counter with the given name. This
+    /// is equivalent to the CSS `counter-increment` property with a negative
+    /// value.
+    Decrement(&'a str, T),
+    /// Sets the value of the innermost counter with the given name. This is
+    /// equivalent to the CSS `counter-set` property.
+    Set(&'a str, T),
+}
+
+/// Tracks counters while walking a document tree, implementing the counter
+/// scoping rules of [CSS Lists and Counters Module Level
+/// 3](https://www.w3.org/TR/css-lists-3/#nested-counters).
+///
+/// Elements are visited in tree order by calling [`CounterTree::enter`] when an
+/// element starts and [`CounterTree::leave`] when it ends. Each element
+/// inherits the counters of its parent and its preceding sibling, and new
+/// counters are instantiated using [`CounterInstruction::Reset`]. When an
+/// element increments or sets a counter that does not exist, a new counter is
+/// instantiated on the element with a value of 0.
+///
+/// Instructions are applied in the same order as CSS applies its properties:
+/// all resets, then all increments and decrements, then all sets.
+///
+/// ```rust
+/// use nominals::{CounterInstruction, CounterTree, Decimal, LetterLower};
+///
+/// let mut tree = CounterTree::<u32>::new();
+/// // <ol>
+/// tree.enter(&[CounterInstruction::Reset("item", 0)]).unwrap();
+/// //   <li>
+/// tree.enter(&[CounterInstruction::Increment("item", 1)]).unwrap();
+/// assert_eq!(tree.format_counter("item", &Decimal), "1");
+/// tree.leave();
+/// //   <li>
+/// tree.enter(&[CounterInstruction::Increment("item", 1)]).unwrap();
+/// //     <ol>
+/// tree.enter(&[CounterInstruction::Reset("item", 0)]).unwrap();
+/// //       <li>
+/// tree.enter(&[CounterInstruction::Increment("item", 1)]).unwrap();
+/// assert_eq!(tree.format_counter("item", &Decimal), "1");
+/// assert_eq!(tree.format_counters("item", ".", &Decimal), "2.1");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CounterTree<T> {
+    elements: Vec<Element<T>>,
+    preceding: Vec<CounterInstance<T>>,
+    next_id: usize,
+}
+
+impl<T> CounterTree<T>
+where
+    T: UnsignedInteger,
+{
+    /// Returns a new tree with no elements.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            elements: alloc::vec![Element {
+                id: 0,
+                counters: Vec::new(),
+                last_child: None,
+            }],
+            preceding: Vec::new(),
+            next_id: 1,
+        }
+    }
+
+    /// Returns the number of elements that have been entered and not left.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.elements.len() - 1
+    }
+
+    /// Enters a new element that is a child of the current element, applying
+    /// `instructions` to the new element's counters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] with a counter's current value if an
+    /// instruction would cause the counter to become negative or exceed the
+    /// range of `T`. The counter is not changed when this occurs. The element
+    /// is entered and all other instructions are still applied.
+    pub fn enter(&mut self, instructions: &[CounterInstruction<'_, T>]) -> Result<(), Error<T>> {
+        let id = self.next_id;
+        self.next_id += 1;
+        let parent = self.current();
+        let parent_id = parent.id;
+
+        // Inherit counters from the parent and preceding sibling, and values
+        // from the element immediately preceding this one in tree order.
+        let mut counters = parent.counters.clone();
+        if let Some(sibling) = &parent.last_child {
+            for counter in sibling {
+                if !counters
+                    .iter()
+                    .any(|existing| existing.name == counter.name)
+                {
+                    counters.push(counter.clone());
+                }
+            }
+        }
+        for source in &self.preceding {
+            if let Some(counter) = counters
+                .iter_mut()
+                .find(|counter| counter.name == source.name && counter.origin == source.origin)
+            {
+                counter.value = source.value.clone();
+            }
+        }
+
+        let mut element = Element {
+            id,
+            counters,
+            last_child: None,
+        };
+        let mut result = Ok(());
+        for instruction in instructions {
+            if let CounterInstruction::Reset(name, value) = instruction {
+                element.instantiate(name, parent_id, value.clone());
+            }
+        }
+        for instruction in instructions {
+            let outcome = match instruction {
+                CounterInstruction::Increment(name, amount) => {
+                    element.innermost(name, parent_id).increment_by(amount)
+                }
+                CounterInstruction::Decrement(name, amount) => {
+                    element.innermost(name, parent_id).decrement_by(amount)
+                }
+                CounterInstruction::Reset(..) | CounterInstruction::Set(..) => Ok(()),
+            };
+            if result.is_ok() {
+                result = outcome;
+            }
+        }
+        for instruction in instructions {
+            if let CounterInstruction::Set(name, value) = instruction {
+                element.innermost(name, parent_id).value = value.clone();
+            }
+        }
+
+        self.preceding.clone_from(&element.counters);
+        self.elements.push(element);
+        result
+    }
+
+    /// Leaves the current element, returning to its parent.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if no elements have been entered.
+    pub fn leave(&mut self) {
+        assert!(self.depth() > 0, "leave called without a matching enter");
+        let element = self.elements.pop().expect("depth checked");
+        let parent = self.elements.last_mut().expect("document is never left");
+        parent.last_child = Some(element.counters);
+    }
+
+    /// Returns the value of the innermost counter named `name` on the current
+    /// element, if it exists.
+    ///
+    /// This is equivalent to the values used by the CSS `counter()` function.
+    #[must_use]
+    pub fn counter(&self, name: &str) -> Option<&T> {
+        self.current()
+            .counters
+            .iter()
+            .rev()
+            .find(|counter| counter.name == name)
+            .map(|counter| &counter.value)
+    }
+
+    /// Returns the values of all counters named `name` on the current element,
+    /// from the outermost to the innermost counter.
+    ///
+    /// This is equivalent to the values used by the CSS `counters()` function.
+    pub fn counters<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a T> + 'a {
+        self.current()
+            .counters
+            .iter()
+            .filter(move |counter| counter.name == name)
+            .map(|counter| &counter.value)
+    }
+
+    /// Formats the innermost counter named `name` using `system`.
+    ///
+    /// If no counter named `name` exists, `0` is formatted. If `system` cannot
+    /// format the value, it is formatted using [`Decimal`](crate::Decimal).
+    pub fn format_counter<N>(&self, name: &str, system: &N) -> NominalString
+    where
+        N: NominalSystem<T> + ?Sized,
+    {
+        system.format_nominal(self.counter(name).cloned().unwrap_or_else(|| T::from(0)))
+    }
+
+    /// Tries to format the innermost counter named `name` using `system`.
+    ///
+    /// If no counter named `name` exists, `0` is formatted.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error returned by `system`.
+    pub fn try_format_counter<N>(&self, name: &str, system: &N) -> Result<NominalString, Error<T>>
+    where
+        N: NominalSystem<T> + ?Sized,
+    {
+        system.try_format_nominal(self.counter(name).cloned().unwrap_or_else(|| T::from(0)))
+    }
+
+    /// Formats all counters named `name` using `system`, joined by
+    /// `separator`.
+    ///
+    /// If no counter named `name` exists, `0` is formatted. If `system` cannot
+    /// format a value, it is formatted using [`Decimal`](crate::Decimal).
+    pub fn format_counters<N>(&self, name: &str, separator: &str, system: &N) -> NominalString
+    where
+        N: NominalSystem<T>,
+    {
+        Outline::new(&[system])
+            .separators(&[separator])
+            .format(&self.counter_values(name))
+    }
+
+    /// Tries to format all counters named `name` using `system`, joined by
+    /// `separator`.
+    ///
+    /// If no counter named `name` exists, `0` is formatted.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error returned by `system`.
+    pub fn try_format_counters<N>(
+        &self,
+        name: &str,
+        separator: &str,
+        system: &N,
+    ) -> Result<NominalString, Error<T>>
+    where
+        N: NominalSystem<T>,
+    {
+        Outline::new(&[system])
+            .separators(&[separator])
+            .try_format(&self.counter_values(name))
+    }
+
+    fn counter_values(&self, name: &str) -> Vec<T> {
+        let mut values = self.counters(name).cloned().collect::<Vec<_>>();
+        if values.is_empty() {
+            values.push(T::from(0));
+        }
+        values
+    }
+
+    fn current(&self) -> &Element<T> {
+        self.elements.last().expect("document is never left")
+    }
+}
+
+impl<T> Default for CounterTree<T>
+where
+    T: UnsignedInteger,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Element<T> {
+    id: usize,
+    counters: Vec<CounterInstance<T>>,
+    last_child: Option<Vec<CounterInstance<T>>>,
+}
+
+impl<T> Element<T>
+where
+    T: UnsignedInteger,
+{
+    fn instantiate(&mut self, name: &str, parent_id: usize, value: T) -> &mut CounterInstance<T> {
+        // A counter instantiated by this element or a preceding sibling is
+        // replaced rather than nested.
+        if let Some(index) = self
+            .counters
+            .iter()
+            .rposition(|counter| counter.name == name)
+        {
+            let innermost = &self.counters[index];
+            if innermost.origin == self.id || innermost.origin_parent == parent_id {
+                self.counters.remove(index);
+            }
+        }
+        self.counters.push(CounterInstance {
+            name: String::from(name),
+            origin: self.id,
+            origin_parent: parent_id,
+            value,
+        });
+        self.counters.last_mut().expect("just pushed")
+    }
+
+    fn innermost(&mut self, name: &str, parent_id: usize) -> &mut CounterInstance<T> {
+        match self
+            .counters
+            .iter()
+            .rposition(|counter| counter.name == name)
+        {
+            Some(index) => &mut self.counters[index],
+            None => self.instantiate(name, parent_id, T::from(0)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct CounterInstance<T> {
+    name: String,
+    origin: usize,
+    origin_parent: usize,
+    value: T,
+}
+
+impl<T> CounterInstance<T>
+where
+    T: UnsignedInteger,
+{
+    fn increment_by(&mut self, amount: &T) -> Result<(), Error<T>> {
+        self.value = self
+            .value
+            .checked_add(amount)
+            .ok_or_else(|| Error::OutOfBounds(self.value.clone()))?;
+        Ok(())
+    }
+
+    fn decrement_by(&mut self, amount: &T) -> Result<(), Error<T>> {
+        if *amount > self.value {
+            return Err(Error::OutOfBounds(self.value.clone()));
+        }
+        self.value -= amount.clone();
+        Ok(())
+    }
+}
+
+#[test]
+fn headings() {
+    use crate::{Decimal, RomanUpper};
+
+    // h1 { counter-increment: chapter; counter-reset: section }
+    let h1 = [
+        CounterInstruction::Increment("chapter", 1_u32),
+        CounterInstruction::Reset("section", 0),
+    ];
+    // h2 { counter-increment: section }
+    let h2 = [CounterInstruction::Increment("section", 1)];
+
+    let mut tree = CounterTree::new();
+    // <body>
+    tree.enter(&[]).unwrap();
+    tree.enter(&h1).unwrap();
+    assert_eq!(tree.format_counter("chapter", &RomanUpper), "I");
+    tree.leave();
+    tree.enter(&h2).unwrap();
+    tree.leave();
+    tree.enter(&h2).unwrap();
+    assert_eq!(tree.format_counters("section", ".", &Decimal), "2");
+    tree.leave();
+    tree.enter(&h1).unwrap();
+    assert_eq!(tree.counter("chapter"), Some(&2));
+    // The section counter created by the first h1 is replaced.
+    assert_eq!(tree.counters("section").collect::<Vec<_>>(), [&0]);
+    tree.leave();
+    tree.enter(&h2).unwrap();
+    assert_eq!(tree.format_counter("chapter", &Decimal), "2");
+    assert_eq!(tree.format_counter("section", &Decimal), "1");
+    tree.leave();
+    tree.leave();
+    assert_eq!(tree.depth(), 0);
+    assert_eq!(tree.counter("chapter"), None);
+    assert_eq!(tree.format_counter("chapter", &Decimal), "0");
+}
+
+#[test]
+fn nested_lists() {
+    use crate::{Decimal, DigitCollection, LetterLower};
+
+    let ol = [CounterInstruction::Reset("item", 0_u32)];
+    let li = [CounterInstruction::Increment("item", 1)];
+    let mut tree = CounterTree::new();
+
+    tree.enter(&ol).unwrap();
+    tree.enter(&li).unwrap();
+    tree.leave();
+    tree.enter(&li).unwrap();
+    tree.enter(&ol).unwrap();
+    tree.enter(&li).unwrap();
+    assert_eq!(tree.format_counters("item", ".", &Decimal), "2.1");
+    tree.leave();
+    tree.enter(&li).unwrap();
+    assert_eq!(tree.format_counters("item", ".", &Decimal), "2.2");
+    assert_eq!(tree.format_counter("item", &LetterLower.one_based()), "b");
+    tree.leave();
+    tree.leave();
+    // The nested list's counter is out of scope after it ends.
+    assert_eq!(tree.format_counters("item", ".", &Decimal), "2");
+    tree.leave();
+    // A sibling of the second item continues the outer counter.
+    tree.enter(&li).unwrap();
+    assert_eq!(tree.format_counters("item", ".", &Decimal), "3");
+    tree.leave();
+    tree.leave();
+}
+
+#[test]
+fn set_and_errors() {
+    use crate::{CircledNumber, Decimal};
+
+    let mut tree = CounterTree::new();
+    tree.enter(&[CounterInstruction::Set("page", 50_u8)])
+        .unwrap();
+    assert_eq!(tree.counter("page"), Some(&50));
+    assert_eq!(tree.format_counter("page", &CircledNumber), "㊿");
+    tree.enter(&[CounterInstruction::Increment("page", 1)])
+        .unwrap();
+    assert_eq!(
+        tree.try_format_counter("page", &CircledNumber),
+        Err(Error::OutOfBounds(51))
+    );
+    tree.leave();
+
+    // Values flow from the preceding element in tree order.
+    tree.enter(&[
+        CounterInstruction::Decrement("page", 52),
+        CounterInstruction::Increment("page", 2),
+    ])
+    .unwrap_err();
+    assert_eq!(tree.format_counter("page", &Decimal), "53");
+    tree.enter(&[CounterInstruction::Increment("page", u8::MAX)])
+        .unwrap_err();
+    assert_eq!(tree.counter("page"), Some(&53));
+}